@@ -97,9 +97,11 @@ local function nvim_decasify_complete (arg_lead, cmd_line, _)
    if arg_index == 1 then
       return filter({ "lower", "sentence", "title", "upper" })
    elseif arg_index == 2 then
-      return filter({ "en", "es", "tr" })
+      return filter(decasify.supported_locales())
    elseif arg_index == 3 then
-      return filter({ "ap", "cmos", "default", "fundeu", "grubber", "rae", "tdk" })
+      local locale = parts[2] or vim.b.decasify_locale or vim.g.decasify_locale or nil
+      local ok, styles = pcall(decasify.supported_styles, locale)
+      return ok and filter(styles) or {}
    else
       return {}
    end
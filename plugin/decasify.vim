@@ -58,9 +58,13 @@ function! s:DecasifyComplete(arg_lead, cmd_line, _) abort
   if l:arg_index == 1
     let l:candidates = ['lower', 'sentence', 'title', 'upper']
   elseif l:arg_index == 2
-    let l:candidates = ['en', 'es', 'tr']
+    let l:candidates = systemlist('decasify --list-locales')
   elseif l:arg_index == 3
-    let l:candidates = ['ap', 'cmos', 'default', 'fundeu', 'grubber', 'rae', 'tdk']
+    let l:locale = get(l:parts, 1, get(b:, 'decasify_locale', get(g:, 'decasify_locale', 'en')))
+    let l:candidates = systemlist('decasify --list-styles --locale ' . shellescape(l:locale))
+    if v:shell_error
+      let l:candidates = []
+    endif
   else
     let l:candidates = []
   endif
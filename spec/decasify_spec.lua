@@ -12,6 +12,8 @@ describe("decasify", function ()
    local lowercase = decasify.lowercase
    local uppercase = decasify.uppercase
    local sentencecase = decasify.sentencecase
+   local supported_styles = decasify.supported_styles
+   local supported_locales = decasify.supported_locales
 
    it("should identify its version", function ()
       local build_env_version
@@ -35,6 +37,11 @@ describe("decasify", function ()
       assert.is_function(sentencecase)
    end)
 
+   it("should provide the capability functions", function ()
+      assert.is_function(supported_styles)
+      assert.is_function(supported_locales)
+   end)
+
    describe("module", function ()
       it("is callable", function ()
          assert.no.error(function ()
@@ -115,6 +122,21 @@ describe("decasify", function ()
          end)
       end)
 
+      it("should balk at style guides unsupported by the locale", function ()
+         assert.error(function ()
+            titlecase("foo", "tr", "cmos")
+         end)
+         assert.error(function ()
+            titlecase("foo", "en", "rae")
+         end)
+      end)
+
+      it("should fall back from unsupported style guides when asked", function ()
+         local text = "foo: a baz"
+         assert.equal("Foo: A Baz", titlecase(text, "en", "rae", { fallback = "default" }))
+         assert.equal("Foo: a Baz", titlecase(text, "en", "rae", { fallback = "closest" }))
+      end)
+
       it("should cooperate with English style guides", function ()
          local text = "foo: a baz"
          local cmos = "Foo: a Baz"
@@ -132,6 +154,20 @@ describe("decasify", function ()
          assert.equal("fOO Bar", titlecase(text, "tr", "default", opts))
       end)
 
+      it("should keep acronyms when asked", function ()
+         local text = "NASA and the iPhone"
+         assert.equal("Nasa and the Iphone", titlecase(text, "en", "cmos"))
+         assert.equal("NASA and the iPhone", titlecase(text, "en", "cmos", { acronyms = "keep" }))
+      end)
+
+      it("should accept options as a string", function ()
+         local text = "NASA and the ios app"
+         assert.equal("NASA and the iOS App", titlecase(text, "en", "cmos", "acronyms=keep;overrides=iOS"))
+         assert.error(function ()
+            titlecase(text, "en", "cmos", "foo=bar")
+         end)
+      end)
+
       it("should be at peace with Turkish characters", function ()
          local result = titlecase("İLKİ ILIK ÖĞLEN", "tr")
          assert.equal("İlki Ilık Öğlen", result)
@@ -141,6 +177,11 @@ describe("decasify", function ()
          local result = titlecase("Sen VE ben ile o", "tr")
          assert.equal("Sen ve Ben ile O", result)
       end)
+
+      it("should pick whether to capitalize Russian subtitles", function ()
+         assert.equal("Война и мир: роман", titlecase("война и мир: роман", "ru"))
+         assert.equal("Война и мир: Роман", titlecase("война и мир: роман", "ru", nil, { subtitles = "capitalize" }))
+      end)
    end)
 
    describe("lowercase", function ()
@@ -201,6 +242,17 @@ describe("decasify", function ()
          local result = uppercase("ilki ılık öğlen", "tr")
          assert.equal("İLKİ ILIK ÖĞLEN", result)
       end)
+
+      it("should pick how to uppercase the German sharp s", function ()
+         assert.equal("DIE STRASSE", uppercase("die Straße", "de"))
+         assert.equal("DIE STRAẞE", case("die Straße", "upper", "de", nil, { eszett = "capital" }))
+      end)
+
+      it("should pick whether to uppercase Georgian to Mtavruli", function ()
+         assert.equal("ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ", uppercase("საქართველო", "ka"))
+         assert.equal("საქართველო", case("საქართველო", "upper", "ka", nil, { mtavruli = "keep" }))
+      end)
+
    end)
 
    describe("sentencecase", function ()
@@ -232,4 +284,21 @@ describe("decasify", function ()
          assert.equal("İlk davransın", result)
       end)
    end)
+
+   describe("capabilities", function ()
+      it("should list style guides for a locale", function ()
+         assert.same({ "default", "tdk" }, supported_styles("tr"))
+      end)
+
+      it("should list locales for a style guide", function ()
+         assert.same({ "es" }, supported_locales("rae"))
+         assert.same({ "en", "tr", "es", "de", "fr", "it", "pt", "nl", "az", "el", "ca", "pl", "cs", "sk", "sr", "hr", "bs", "ru", "uk", "ga", "cy", "lt", "ka", "pt-pt" }, supported_locales())
+      end)
+
+      it("should balk at unparsable values", function ()
+         assert.error(function ()
+            supported_styles("foo")
+         end)
+      end)
+   end)
 end)
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{ReservedWords, apply_overrides, keep_acronyms};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+use unicode_titlecase::tr_az::StrTrAzCasing;
+
+// Azerbaijani shares the dotted and dotless I casing rules with Turkish
+pub use crate::tr::{lowercase, sentencecase, uppercase};
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[StyleGuide::LanguageDefault];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::LanguageDefault
+}
+
+// Conjunctions and particles
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "amma", "ancaq", "da", "də", "həm", "ilə", "ki", "lakin", "nə", "və", "ya", "yaxud",
+        "yoxsa",
+    ])
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => titlecase_azerbaijani(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::AZ,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+fn titlecase_azerbaijani(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase_tr_az());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let mut done_first = false;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if !locked[i] {
+                    let lower = word.word.to_lowercase_tr_az();
+                    word.word = match done_first && RESERVED.contains(&lower) {
+                        true => lower,
+                        false => word.word.to_titlecase_tr_or_az_lower_rest(),
+                    };
+                }
+                done_first = true;
+            }
+        });
+    chunk.into()
+}
@@ -7,8 +7,9 @@ use std::io::BufRead;
 
 use decasify::cli::{Cli, STYLES};
 use decasify::types::Result;
-use decasify::{lowercase, sentencecase, titlecase, uppercase};
-use decasify::{Case, Locale, StyleGuide, StyleOptions, StyleOptionsBuilder};
+use decasify::Decasifier;
+use decasify::{supported_locales, supported_styles};
+use decasify::{Case, Locale, StyleGuide, StyleOptions, Word};
 
 fn main() -> Result<()> {
     let version = option_env!("VERGEN_GIT_DESCRIBE").unwrap_or_else(|| env!("CARGO_PKG_VERSION"));
@@ -26,13 +27,25 @@ fn main() -> Result<()> {
         .get_one::<StyleGuide>("style")
         .unwrap_or(&StyleGuide::default())
         .to_owned();
-    let opts = if let Some(overrides) = matches.get_many::<String>("overrides") {
-        StyleOptionsBuilder::new()
-            .overrides(overrides.collect())
-            .build()
-    } else {
-        StyleOptions::default()
-    };
+    if matches.get_flag("list_styles") {
+        supported_styles(locale)
+            .iter()
+            .for_each(|style| println!("{style}"));
+        return Ok(());
+    }
+    if matches.get_flag("list_locales") {
+        supported_locales(style)
+            .iter()
+            .for_each(|locale| println!("{locale}"));
+        return Ok(());
+    }
+    let mut opts = matches
+        .get_one::<StyleOptions>("options")
+        .cloned()
+        .unwrap_or_default();
+    if let Some(overrides) = matches.get_many::<String>("overrides") {
+        opts.overrides = Some(overrides.map(Word::from).collect());
+    }
     match matches.contains_id("input") {
         true => {
             let input: Vec<String> = matches
@@ -63,14 +76,9 @@ fn process<I: IntoIterator<Item = String>>(
     style: StyleGuide,
     opts: StyleOptions,
 ) -> Result<()> {
+    let decasifier = Decasifier::new(case, locale, style, opts)?;
     for string in strings {
-        let output = match case {
-            Case::Title => titlecase(string, locale, style.clone(), opts.clone())?,
-            Case::Lower => lowercase(string, locale)?,
-            Case::Upper => uppercase(string, locale)?,
-            Case::Sentence => sentencecase(string, locale)?,
-            _ => unreachable!(),
-        };
+        let output = decasifier.apply(string)?;
         println!("{output}");
     }
     Ok(())
@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::Chunk;
+use crate::sr::titlecase_regional;
+use crate::types::{Locale, Result, StyleGuide, StyleOptions};
+
+// Bosnian shares its alphabet, digraphs, and title style with Serbian
+pub use crate::sr::{STYLE_GUIDES, closest_style, lowercase, sentencecase, uppercase};
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    titlecase_regional(chunk, style, opts, Locale::BS)
+}
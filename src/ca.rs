@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{
+    ReservedWords, apply_overrides, keep_acronyms, map_compound, split_elisions,
+    titlecase_sentence_style,
+};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use regex::{Captures, Regex};
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[
+    StyleGuide::LanguageDefault,
+    StyleGuide::InstitutDEstudisCatalans,
+    StyleGuide::MajorWords,
+];
+
+pub fn closest_style(style: StyleGuide) -> StyleGuide {
+    match style.is_sentence_style() {
+        true => StyleGuide::InstitutDEstudisCatalans,
+        false => StyleGuide::MajorWords,
+    }
+}
+
+// The geminate l written with a full stop where a middle dot wasn't available: "col.lecció". It
+// always sits between two vowels.
+static LEGACY_GEMINATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)([aeiouàèéíïòóúü])(l)\.(l)([aeiouàèéíïòóúü])").unwrap());
+
+static ELISIONS: LazyLock<ReservedWords> =
+    LazyLock::new(|| ReservedWords::from_slice(&["d", "l", "m", "n", "s", "t"]));
+
+// Articles, prepositions, their contractions, and conjunctions. Elided forms are listed without
+// their apostrophe.
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "a", "al", "als", "amb", "de", "del", "dels", "d", "el", "els", "en", "entre", "es", "i",
+        "l", "la", "les", "o", "pel", "pels", "per", "sa", "ses", "sense", "sobre", "un", "una",
+        "unes", "uns",
+    ])
+});
+
+// Weak pronouns attached to verbs with a hyphen: "digues-m'ho", "posa-t'hi", "fes-ho"
+static ENCLITICS: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "el", "els", "em", "en", "ens", "es", "et", "hi", "ho", "la", "les", "li", "lo", "los",
+        "me", "ne", "nos", "se", "te", "us", "vos",
+    ])
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    let chunk = normalize_geminates(chunk);
+    Ok(match style {
+        // The IEC recommends styling titles like sentences
+        StyleGuide::LanguageDefault => titlecase_sentence_style(chunk, opts),
+        StyleGuide::InstitutDEstudisCatalans => titlecase_sentence_style(chunk, opts),
+        StyleGuide::MajorWords => titlecase_major_words(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::CA,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+pub fn lowercase(chunk: Chunk) -> String {
+    crate::generics::lowercase(normalize_geminates(chunk))
+}
+
+pub fn uppercase(chunk: Chunk) -> String {
+    crate::generics::uppercase(normalize_geminates(chunk))
+}
+
+pub fn sentencecase(chunk: Chunk) -> String {
+    crate::generics::sentencecase(normalize_geminates(chunk))
+}
+
+// Replace the legacy full stop in geminate l with a proper punt volat so it reads "L·L" in capitals
+// instead of being mistaken for the end of a sentence. Both letters have to be in the same case,
+// and anything that looks like an address is left alone: "hotel.Lleida", "mail.lleida.cat".
+fn normalize_geminates(mut chunk: Chunk) -> Chunk {
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            if is_address(&word.word) {
+                return;
+            }
+            word.word = LEGACY_GEMINATE
+                .replace_all(&word.word, |caps: &Captures| match caps[2] == caps[3] {
+                    true => format!("{}{}·{}{}", &caps[1], &caps[2], &caps[3], &caps[4]),
+                    false => caps[0].to_owned(),
+                })
+                .into_owned();
+        }
+    });
+    chunk
+}
+
+fn is_address(word: &str) -> bool {
+    word.contains(['/', '@', ':']) || word.matches('.').count() > 1
+}
+
+fn titlecase_major_words(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    split_elisions(&mut chunk, &ELISIONS, &mut locked);
+    let phrases = RESERVED.match_phrases(&chunk);
+    let mut done_first = false;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if !locked[i] {
+                    let stem = word.word.trim_end_matches(['\'', '’']);
+                    let reserved = done_first && (phrases[i] || RESERVED.contains(stem));
+                    word.word = map_compound(&word.word, |part_idx, part| {
+                        let enclitic = part_idx > 0
+                            && (part.contains(['\'', '’']) || ENCLITICS.contains(part));
+                        match reserved || enclitic {
+                            true => part.to_lowercase(),
+                            false => part.to_titlecase_lower_rest(),
+                        }
+                    });
+                }
+                done_first = true;
+            }
+        });
+    chunk.into()
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 #[cfg(build)]
-use crate::{Case, Locale, StyleGuide};
+use crate::{Case, Locale, StyleGuide, StyleOptions};
 
 use clap::builder::styling::{AnsiColor, Styles};
 use clap::{builder, Parser};
@@ -52,10 +52,33 @@ pub struct Cli {
     ///
     /// Override the output case of specific words regardless of what the casing function or style
     /// guide would normally do with them. The case used to specify these words is used in the
-    /// output regardless of the target style or input case.
+    /// output regardless of the target style or input case. Overrides containing spaces are
+    /// matched as whole phrases.
     #[clap(short = 'O', long, num_args(1..))]
     pub overrides: Option<Vec<String>>,
 
+    /// Style options
+    ///
+    /// Fine tune how the style guide is applied with `key=value` pairs separated by semicolons,
+    /// for example `acronyms=keep;fallback=closest`. Overrides may also be set here as a comma
+    /// separated list (with any commas or semicolons in them escaped with a backslash), but any
+    /// given with the dedicated option take precedence.
+    #[clap(long, default_value_t)]
+    pub options: StyleOptions,
+
+    /// List supported style guides
+    ///
+    /// Print the style guides implemented for the selected locale (one per line) and exit.
+    #[clap(long, conflicts_with = "list_locales")]
+    pub list_styles: bool,
+
+    /// List supported locales
+    ///
+    /// Print the locales implementing the selected style guide (one per line) and exit. With the
+    /// default style guide this lists every supported locale.
+    #[clap(long)]
+    pub list_locales: bool,
+
     /// The input string or strings (note STDIN also accepted)
     ///
     /// Note that all input arguments are processed together joined with a space, and STDIN streams
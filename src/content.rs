@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 use regex::Regex;
+use std::sync::LazyLock;
 use std::{borrow::Cow, fmt, fmt::Display, str::FromStr};
 use unicode_titlecase::StrTitleCase;
 
@@ -17,24 +18,78 @@ pub struct Chunk {
 #[non_exhaustive]
 pub enum Segment {
     Separator(String),
+    Punctuation(String),
     Word(Word),
 }
 
+static SEGMENTS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?<separator>\p{Whitespace}+)|(?<word>\P{Whitespace}+)").unwrap()
+});
+
+// Quotes, brackets, inverted marks and the like hugging a word are not part of it, but anything
+// between the first and last letter (apostrophes, hyphens, ampersands) is.
+static PUNCTUATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?<leading>\p{P}*)(?<core>.*?)(?<trailing>\p{P}*)$").unwrap());
+
 fn split_chunk(s: &str) -> Chunk {
     let mut segments: Vec<Segment> = Vec::new();
-    let captures = Regex::new(r"(?<separator>\p{Whitespace}+)|(?<word>\P{Whitespace}+)").unwrap();
-    for capture in captures.captures_iter(s) {
+    for capture in SEGMENTS.captures_iter(s) {
         if let Some(m) = capture.name("separator") {
             segments.push(Segment::Separator(m.as_str().to_string()));
         } else if let Some(m) = capture.name("word") {
-            segments.push(Segment::Word(Word {
-                word: m.as_str().to_owned(),
-            }));
+            split_word(m.as_str(), &mut segments);
         }
     }
     Chunk { segments }
 }
 
+/// Strip any leading and trailing punctuation the same way words in a chunk would be split
+pub fn word_core(s: &str) -> &str {
+    PUNCTUATION
+        .captures(s)
+        .and_then(|parts| parts.name("core"))
+        .map_or(s, |m| m.as_str())
+}
+
+fn split_word(s: &str, segments: &mut Vec<Segment>) {
+    let Some(parts) = PUNCTUATION.captures(s) else {
+        segments.push(Segment::Word(Word::from(s)));
+        return;
+    };
+    let leading = parts.name("leading").map_or("", |m| m.as_str());
+    let core = parts.name("core").map_or("", |m| m.as_str());
+    let trailing = parts.name("trailing").map_or("", |m| m.as_str());
+    if !leading.is_empty() {
+        segments.push(Segment::Punctuation(leading.to_owned()));
+    }
+    if !core.is_empty() {
+        segments.push(Segment::Word(Word::from(core)));
+    }
+    if !trailing.is_empty() {
+        segments.push(Segment::Punctuation(trailing.to_owned()));
+    }
+}
+
+impl Chunk {
+    /// Iterate over the words in a chunk along with their segment indices
+    pub fn words(&self) -> impl Iterator<Item = (usize, &Word)> {
+        self.segments
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, segment)| match segment {
+                Segment::Word(word) => Some((idx, word)),
+                _ => None,
+            })
+    }
+
+    /// Check whether two segments are only separated by whitespace
+    pub fn adjacent(&self, from: usize, to: usize) -> bool {
+        self.segments[from + 1..to]
+            .iter()
+            .all(|segment| matches!(segment, Segment::Separator(_)))
+    }
+}
+
 impl From<String> for Chunk {
     fn from(s: String) -> Self {
         split_chunk(s.as_ref())
@@ -89,6 +144,7 @@ impl Display for Segment {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Segment::Separator(string) => fmt.write_str(string)?,
+            Segment::Punctuation(string) => fmt.write_str(string)?,
             Segment::Word(word) => fmt.write_str(word.to_string().as_ref())?,
         };
         Ok(())
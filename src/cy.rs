@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{ReservedWords, apply_overrides, join_digraphs, keep_acronyms};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub use crate::generics::{lowercase, uppercase};
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[StyleGuide::LanguageDefault];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::LanguageDefault
+}
+
+const DIGRAPHS: &[&str] = &["ch", "dd", "ff", "ng", "ll", "ph", "rh", "th"];
+
+// Articles, prepositions, and conjunctions. Words with a clitic attached ("i'r", "a'r") are
+// checked by the part before the apostrophe.
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "a", "ac", "am", "ar", "at", "gan", "i", "neu", "o", "ond", "y", "yn", "yr",
+    ])
+});
+
+// Clitic forms of the article and pronouns ('r, 'n, 'i) that got separated from the word they lean
+// on. The apostrophe gets split off as punctuation, so they are listed without it.
+static CLITICS: LazyLock<ReservedWords> =
+    LazyLock::new(|| ReservedWords::from_slice(&["i", "n", "r"]));
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => recase(chunk, opts, Some(&RESERVED)),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::CY,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+pub fn sentencecase(chunk: Chunk, opts: &StyleOptions) -> String {
+    recase(chunk, opts, None)
+}
+
+// Digraphs are single letters, so only their first character ever gets capitalized in title case
+// ("Llanelli", "Rhydaman"). A clitic never takes a capital, even at the start.
+fn recase(mut chunk: Chunk, opts: &StyleOptions, reserved: Option<&ReservedWords>) -> String {
+    join_digraphs(&mut chunk, |pair| DIGRAPHS.contains(&pair));
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let mut done_first = false;
+    let mut elided = false;
+    for (i, segment) in chunk.segments.iter_mut().enumerate() {
+        let word = match segment {
+            Segment::Word(word) => word,
+            Segment::Punctuation(punctuation) => {
+                elided = punctuation.ends_with(['\'', '’']);
+                continue;
+            }
+            Segment::Separator(_) => {
+                elided = false;
+                continue;
+            }
+        };
+        if locked[i] {
+            done_first = true;
+            continue;
+        }
+        if elided && CLITICS.contains(&word.word) {
+            word.word = word.word.to_lowercase();
+            continue;
+        }
+        let stem = word.word.split(['\'', '’']).next().unwrap_or_default();
+        let capitalize = !done_first || reserved.is_some_and(|reserved| !reserved.contains(stem));
+        word.word = match capitalize {
+            true => word.word.to_titlecase_lower_rest(),
+            false => word.word.to_lowercase(),
+        };
+        done_first = true;
+    }
+    chunk.into()
+}
@@ -0,0 +1,318 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{
+    IsReserved, ReservedWords, apply_overrides, is_mixed_case, keep_acronyms, map_compound,
+};
+use crate::types::{Eszett, Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[
+    StyleGuide::LanguageDefault,
+    StyleGuide::DeutschesInstitutFurNormung,
+];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::DeutschesInstitutFurNormung
+}
+
+// Words that are never nouns, used to tell whether a capitalized word in the input is a noun or
+// just an artifact of some other capitalization scheme.
+static FUNCTION_WORDS: LazyLock<ReservedWords> = LazyLock::new(|| {
+    let mut reserved = ReservedWords::from_slice(&[
+        "aber", "alle", "als", "am", "an", "auch", "auf", "aus", "bei", "beim", "bis", "da", "das",
+        "dass", "dem", "den", "denn", "der", "des", "dich", "die", "doch", "du", "durch", "ein",
+        "eine", "einem", "einen", "einer", "eines", "er", "es", "euch", "für", "gegen", "hinter",
+        "ich", "ihr", "im", "in", "ins", "ist", "mein", "mich", "mit", "nach", "neben", "nicht",
+        "noch", "nur", "ob", "oder", "ohne", "schon", "sehr", "sein", "seit", "sich", "sie",
+        "sind", "so", "sondern", "über", "um", "und", "uns", "unter", "vom", "von", "vor", "war",
+        "weil", "wenn", "wie", "wir", "zu", "zum", "zur", "zwischen",
+    ]);
+    // Determiners and pronouns in all their inflected forms
+    for stem in [
+        "dein", "dies", "ein", "euer", "eur", "ihr", "jed", "jen", "kein", "mein", "sein", "unser",
+        "welch",
+    ] {
+        for ending in ["", "e", "em", "en", "er", "es"] {
+            reserved.add_slice(&[&format!("{stem}{ending}")]);
+        }
+    }
+    reserved
+});
+
+// Common nouns that don't give themselves away with a suffix
+static NOUN_LEXICON: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "abend",
+        "adresse",
+        "angst",
+        "antwort",
+        "arbeit",
+        "art",
+        "arzt",
+        "auge",
+        "auto",
+        "bahn",
+        "baum",
+        "berg",
+        "bericht",
+        "bild",
+        "blatt",
+        "blume",
+        "boden",
+        "brief",
+        "brot",
+        "bruder",
+        "buch",
+        "bund",
+        "dach",
+        "daten",
+        "dienst",
+        "ding",
+        "dorf",
+        "eltern",
+        "ende",
+        "erde",
+        "fall",
+        "familie",
+        "farbe",
+        "feld",
+        "fenster",
+        "feuer",
+        "film",
+        "firma",
+        "fisch",
+        "fluss",
+        "form",
+        "frage",
+        "frau",
+        "freund",
+        "frieden",
+        "garten",
+        "geld",
+        "geschichte",
+        "gesetz",
+        "gesicht",
+        "glas",
+        "glück",
+        "gott",
+        "gras",
+        "grund",
+        "gruppe",
+        "haar",
+        "hand",
+        "haus",
+        "herr",
+        "herz",
+        "himmel",
+        "hof",
+        "hund",
+        "idee",
+        "insel",
+        "jahr",
+        "kampf",
+        "karte",
+        "katze",
+        "kind",
+        "kirche",
+        "klasse",
+        "kopf",
+        "kosten",
+        "kraft",
+        "krieg",
+        "kunst",
+        "küche",
+        "land",
+        "leute",
+        "licht",
+        "liebe",
+        "luft",
+        "mail",
+        "mann",
+        "markt",
+        "meer",
+        "mensch",
+        "milch",
+        "minute",
+        "monat",
+        "musik",
+        "mutter",
+        "nacht",
+        "name",
+        "natur",
+        "nummer",
+        "paar",
+        "pferd",
+        "platz",
+        "politik",
+        "preis",
+        "presse",
+        "problem",
+        "punkt",
+        "raum",
+        "recht",
+        "regen",
+        "reise",
+        "rolle",
+        "sache",
+        "schiff",
+        "schrift",
+        "schule",
+        "schwester",
+        "see",
+        "seite",
+        "sohn",
+        "sonne",
+        "spiel",
+        "sprache",
+        "staat",
+        "stadt",
+        "stein",
+        "stern",
+        "stimme",
+        "strasse",
+        "straße",
+        "stunde",
+        "stück",
+        "system",
+        "tag",
+        "teil",
+        "text",
+        "thema",
+        "tisch",
+        "tochter",
+        "ton",
+        "tür",
+        "uhr",
+        "vater",
+        "vogel",
+        "volk",
+        "wald",
+        "wasser",
+        "welt",
+        "wetter",
+        "wind",
+        "woche",
+        "wort",
+        "zeit",
+        "ziel",
+        "zimmer",
+        "zug",
+        "zukunft",
+    ])
+});
+
+// Nouns that are common as the last element of a compound and don't end any adjectives, adverbs, or
+// participles, so "Bahnhof" is found from "Hof". Endings like "zeit" or "recht" are left out since
+// they also make words like "jederzeit" or "senkrecht".
+const COMPOUND_HEADS: &[&str] = &[
+    "bahn", "baum", "berg", "bericht", "brief", "buch", "dienst", "dorf", "feld", "fenster",
+    "garten", "haus", "hof", "karte", "kirche", "markt", "musik", "platz", "schiff", "schule",
+    "spiel", "stadt", "strasse", "straße", "stück", "tisch", "wald", "wasser", "zimmer", "zug",
+];
+
+const NOUN_SUFFIXES: &[&str] = &[
+    "heit", "ion", "ismus", "keit", "lein", "ling", "nis", "schaft", "tät", "tum", "ung",
+];
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => recase(chunk, opts, true),
+        StyleGuide::DeutschesInstitutFurNormung => recase(chunk, opts, true),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::DE,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+pub fn lowercase(chunk: Chunk, opts: &StyleOptions) -> String {
+    recase(chunk, opts, false)
+}
+
+pub fn uppercase(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = match opts.eszett {
+                Eszett::Capital => word.word.replace('ß', "ẞ").to_uppercase(),
+                _ => word.word.to_uppercase(),
+            }
+        }
+    });
+    chunk.into()
+}
+
+pub fn sentencecase(chunk: Chunk, opts: &StyleOptions) -> String {
+    recase(chunk, opts, true)
+}
+
+// German titles follow the same orthography as running text (DIN 5008), so title case, sentence
+// case, and lower case only differ in whether the first word gets capitalized. Nouns always do.
+fn recase(mut chunk: Chunk, opts: &StyleOptions, capitalize_first: bool) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    // Capitals in the input only tell nouns apart when the rest is in lower case
+    let trust_input = is_mixed_case(&chunk, &FUNCTION_WORDS);
+    let mut done_first = !capitalize_first;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if locked[i] {
+                    done_first = true;
+                    return;
+                }
+                let capitalized = trust_input
+                    && word.word.chars().next().is_some_and(char::is_uppercase)
+                    && !word.is_reserved(&FUNCTION_WORDS);
+                word.word = if capitalized || is_noun(&word.word) {
+                    capitalize_compound(&word.word, trust_input)
+                } else if !done_first {
+                    word.word.to_titlecase_lower_rest()
+                } else {
+                    word.word.to_lowercase()
+                };
+                done_first = true;
+            }
+        });
+    chunk.into()
+}
+
+fn is_noun(word: &str) -> bool {
+    let word = word.to_lowercase();
+    // Compounds take their word class from the last element
+    let head = word
+        .rsplit(['-', '\u{2010}', '\u{2013}'])
+        .next()
+        .unwrap_or_default();
+    let has_stem = |ending: &str| {
+        head.strip_suffix(ending)
+            .is_some_and(|stem| stem.chars().count() >= 3)
+    };
+    NOUN_LEXICON.contains(head)
+        || COMPOUND_HEADS.iter().any(|noun| has_stem(noun))
+        || NOUN_SUFFIXES.iter().any(|suffix| has_stem(suffix))
+}
+
+// Hyphenated compounds start with a capital and keep the head noun and any other nouns in them
+// capitalized, e.g. "Ad-hoc-Lösung" or "E-Mail-Adresse".
+fn capitalize_compound(word: &str, trust_input: bool) -> String {
+    let last = word
+        .split(['-', '\u{2010}', '\u{2013}'])
+        .filter(|part| !part.is_empty())
+        .count()
+        .saturating_sub(1);
+    map_compound(word, |i, part| {
+        let capitalized = trust_input && part.chars().next().is_some_and(char::is_uppercase);
+        match i == 0 || i == last || capitalized || is_noun(part) {
+            true => part.to_titlecase_lower_rest(),
+            false => part.to_lowercase(),
+        }
+    })
+}
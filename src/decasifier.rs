@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::Chunk;
+use crate::generics::prepare_overrides;
+use crate::types::{Case, Error, Locale, Result, StyleGuide, StyleOptions};
+use crate::{
+    lowercase_with_options, resolve_style, sentencecase_with_options, titlecase_resolved,
+    uppercase_with_options,
+};
+
+/// A reusable case converter with all of its settings parsed and validated up front
+///
+/// Use this instead of [`case`](crate::case) when the same conversion is going to be applied to
+/// many strings. Parsing the locale, case, style guide, and options and checking the style guide
+/// against the locale only happens once when the converter is built, as does getting any overrides
+/// ready to match.
+#[derive(Clone, Debug)]
+pub struct Decasifier {
+    case: Case,
+    locale: Locale,
+    style: StyleGuide,
+    opts: StyleOptions,
+}
+
+impl Decasifier {
+    pub fn new<TC, TL, TS, TO>(case: TC, locale: TL, style: TS, opts: TO) -> Result<Self>
+    where
+        TC: TryInto<Case>,
+        TL: TryInto<Locale>,
+        TS: TryInto<StyleGuide>,
+        TO: TryInto<StyleOptions>,
+        Error: From<TC::Error>,
+        Error: From<TL::Error>,
+        Error: From<TS::Error>,
+        Error: From<TO::Error>,
+    {
+        let case: Case = case.try_into()?;
+        let locale: Locale = locale.try_into()?;
+        let style: StyleGuide = style.try_into()?;
+        let mut opts: StyleOptions = opts.try_into()?;
+        prepare_overrides(&mut opts.overrides);
+        // Style guides only come into play for title casing, other cases shouldn't fail on them.
+        let style = match case {
+            Case::Title => resolve_style(locale, style, opts.fallback)?,
+            _ => style,
+        };
+        Ok(Self {
+            case,
+            locale,
+            style,
+            opts,
+        })
+    }
+
+    /// Convert a string using the prepared settings
+    pub fn apply(&self, chunk: impl Into<Chunk>) -> Result<String> {
+        let chunk: Chunk = chunk.into();
+        match self.case {
+            Case::Lower => Ok(lowercase_with_options(chunk, self.locale, &self.opts)),
+            Case::Upper => Ok(uppercase_with_options(chunk, self.locale, &self.opts)),
+            Case::Sentence => Ok(sentencecase_with_options(chunk, self.locale, &self.opts)),
+            Case::Title => titlecase_resolved(chunk, self.locale, self.style.clone(), &self.opts),
+        }
+    }
+
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    pub fn style(&self) -> &StyleGuide {
+        &self.style
+    }
+
+    pub fn options(&self) -> &StyleOptions {
+        &self.opts
+    }
+}
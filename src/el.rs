@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{apply_overrides, keep_acronyms};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use unicode_titlecase::StrTitleCase;
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[StyleGuide::LanguageDefault];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::LanguageDefault
+}
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => titlecase_greek(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::EL,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+// Greek titles are styled like sentences. A capital initial keeps its accent ("Άλλος") since
+// only words in all capitals drop them.
+fn titlecase_greek(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let mut done_first = false;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if !locked[i] {
+                    word.word = match done_first {
+                        true => lowercase_greek(&word.word),
+                        false => fix_final_sigma(&word.word.to_titlecase_lower_rest()),
+                    };
+                }
+                done_first = true;
+            }
+        });
+    chunk.into()
+}
+
+pub fn lowercase(mut chunk: Chunk) -> String {
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = lowercase_greek(&word.word)
+        }
+    });
+    chunk.into()
+}
+
+pub fn uppercase(mut chunk: Chunk) -> String {
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = uppercase_greek(&word.word)
+        }
+    });
+    chunk.into()
+}
+
+pub fn sentencecase(mut chunk: Chunk) -> String {
+    let mut done_first = false;
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = if !done_first {
+                done_first = true;
+                fix_final_sigma(&word.word.to_titlecase_lower_rest())
+            } else {
+                lowercase_greek(&word.word)
+            }
+        }
+    });
+    chunk.into()
+}
+
+fn lowercase_greek(word: &str) -> String {
+    fix_final_sigma(&word.to_lowercase())
+}
+
+// Use the final form of sigma at the end of a word and the medial form anywhere else, regardless of
+// which form the input had
+fn fix_final_sigma(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| match c {
+            'σ' | 'ς' => {
+                let after_letter = i > 0 && chars[i - 1].is_alphabetic();
+                let before_letter = chars.get(i + 1).is_some_and(|c| c.is_alphabetic());
+                match after_letter && !before_letter {
+                    true => 'ς',
+                    false => 'σ',
+                }
+            }
+            c => c,
+        })
+        .collect()
+}
+
+// Words in all capitals drop the tonos. If that would make a stressed vowel and the following
+// vowel read as a diphthong, the second vowel gets a dialytika instead: "Μάιος" → "ΜΑΪΟΣ".
+fn uppercase_greek(word: &str) -> String {
+    let mut output = String::with_capacity(word.len());
+    let mut previous: Option<(char, bool)> = None;
+    for c in word.chars() {
+        if c == '\u{0301}' {
+            previous = previous.map(|(base, _)| (base, true));
+            continue;
+        }
+        let (base, stressed) = strip_tonos(c);
+        let base = match previous {
+            Some((previous, true)) if forms_diphthong(previous, base) => add_dialytika(base),
+            _ => base,
+        };
+        output.extend(base.to_uppercase());
+        previous = Some((base.to_lowercase().next().unwrap_or(base), stressed));
+    }
+    output
+}
+
+fn strip_tonos(c: char) -> (char, bool) {
+    match c {
+        'ά' => ('α', true),
+        'έ' => ('ε', true),
+        'ή' => ('η', true),
+        'ί' => ('ι', true),
+        'ό' => ('ο', true),
+        'ύ' => ('υ', true),
+        'ώ' => ('ω', true),
+        'ΐ' => ('ϊ', true),
+        'ΰ' => ('ϋ', true),
+        'Ά' => ('Α', true),
+        'Έ' => ('Ε', true),
+        'Ή' => ('Η', true),
+        'Ί' => ('Ι', true),
+        'Ό' => ('Ο', true),
+        'Ύ' => ('Υ', true),
+        'Ώ' => ('Ω', true),
+        c => (c, false),
+    }
+}
+
+fn forms_diphthong(first: char, second: char) -> bool {
+    matches!(
+        (first, second),
+        ('α' | 'ε' | 'ο' | 'υ', 'ι' | 'Ι') | ('α' | 'ε' | 'η' | 'ο', 'υ' | 'Υ')
+    )
+}
+
+fn add_dialytika(c: char) -> char {
+    match c {
+        'ι' => 'ϊ',
+        'υ' => 'ϋ',
+        'Ι' => 'Ϊ',
+        'Υ' => 'Ϋ',
+        c => c,
+    }
+}
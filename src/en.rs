@@ -2,55 +2,255 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 use crate::content::{Chunk, Segment};
-use crate::get_override;
-use crate::types::{StyleGuide, StyleOptions, Word};
+use crate::generics::{
+    IsReserved, ReservedWords, apply_overrides, is_mixed_case, keep_acronyms, map_compound,
+};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu, Word};
 
-use regex::Regex;
+use std::sync::LazyLock;
 use titlecase::titlecase as gruber_titlecase;
 use unicode_titlecase::StrTitleCase;
 
 pub use crate::generics::{lowercase, sentencecase, uppercase};
 
-pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: StyleOptions) -> String {
-    match style {
+pub const STYLE_GUIDES: &[StyleGuide] = &[
+    StyleGuide::LanguageDefault,
+    StyleGuide::AssociatedPress,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleGuide::DaringFireball,
+];
+
+pub fn closest_style(style: StyleGuide) -> StyleGuide {
+    // Foreign guides that capitalize major words are closest in spirit to CMOS
+    match style.is_sentence_style() {
+        true => StyleGuide::LanguageDefault,
+        false => StyleGuide::ChicagoManualOfStyle,
+    }
+}
+
+// AP lowercases articles, coordinating conjunctions, and prepositions of three or fewer letters.
+// Longer prepositions and conjunctions are capitalized like any other word.
+static AP_RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "off", "on", "or",
+        "out", "per", "so", "the", "to", "up", "via", "yet",
+    ])
+});
+
+static CMOS_RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    // Articles
+    let mut reserved = ReservedWords::from_slice(&["a", "an", "the"]);
+    // Conjunctions
+    reserved.add_slice(&[
+        "after",
+        "although",
+        "and",
+        "as",
+        "because",
+        "before",
+        "both",
+        "but",
+        "either",
+        "for",
+        "if",
+        "lest",
+        "neither",
+        "nor",
+        "once",
+        "only",
+        "or",
+        "since",
+        "so",
+        "supposing",
+        "than",
+        "that",
+        "though",
+        "till",
+        "unless",
+        "until",
+        "when",
+        "whenever",
+        "where",
+        "whereas",
+        "wherever",
+        "whether",
+        "while",
+        "yet",
+    ]);
+    // Multi-word conjunctions
+    reserved.add_slice(&[
+        "as if",
+        "as long as",
+        "as much as",
+        "as soon as",
+        "as though",
+        "by the time",
+        "even if",
+        "even though",
+        "in case",
+        "in order that",
+        "in the event that",
+        "not only",
+        "now that",
+        "only if",
+        "provided that",
+        "whether or not",
+    ]);
+    // Prepositions
+    reserved.add_slice(&[
+        "about",
+        "above",
+        "across",
+        "after",
+        "against",
+        "along",
+        "among",
+        "around",
+        "at",
+        "before",
+        "behind",
+        "between",
+        "beyond",
+        "but",
+        "by",
+        "concerning",
+        "despite",
+        "down",
+        "during",
+        "except",
+        "following",
+        "for",
+        "from",
+        "in",
+        "including",
+        "into",
+        "like",
+        "near",
+        "of",
+        "off",
+        "on",
+        "onto",
+        "out",
+        "over",
+        "past",
+        "plus",
+        "since",
+        "throughout",
+        "to",
+        "towards",
+        "under",
+        "until",
+        "up",
+        "upon",
+        "with",
+        "within",
+        "without",
+    ]);
+    reserved
+});
+
+static CMOS_PREFIXES: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "anti", "bi", "co", "counter", "de", "e", "extra", "hyper", "inter", "intra", "macro",
+        "micro", "mid", "mini", "multi", "neo", "non", "post", "pre", "pro", "pseudo", "re",
+        "semi", "sub", "super", "trans", "tri", "ultra", "un",
+    ])
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
         StyleGuide::LanguageDefault => titlecase_gruber(chunk, opts),
         StyleGuide::AssociatedPress => titlecase_ap(chunk, opts),
         StyleGuide::ChicagoManualOfStyle => titlecase_cmos(chunk, opts),
         StyleGuide::DaringFireball => titlecase_gruber(chunk, opts),
-        _ => todo!("English implementation doesn't support this style guide."),
-    }
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::EN,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+fn titlecase_ap(chunk: Chunk, opts: &StyleOptions) -> String {
+    titlecase_major_words(chunk, opts, &AP_RESERVED, None)
 }
 
-fn titlecase_ap(_chunk: Chunk, _opts: StyleOptions) -> String {
-    todo!("AP style guide not implemented, string returned as-is!");
+fn titlecase_cmos(chunk: Chunk, opts: &StyleOptions) -> String {
+    titlecase_major_words(chunk, opts, &CMOS_RESERVED, Some(&CMOS_PREFIXES))
 }
 
-fn titlecase_cmos(chunk: Chunk, _opts: StyleOptions) -> String {
-    let mut chunk = chunk.clone();
-    let mut words = chunk
+// Capitalize everything except reserved words and phrases, but always capitalize the first and
+// last words.
+fn titlecase_major_words(
+    mut chunk: Chunk,
+    opts: &StyleOptions,
+    reserved: &ReservedWords,
+    prefixes: Option<&ReservedWords>,
+) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let phrases = reserved.match_phrases(&chunk);
+    let trust_input = is_mixed_case(&chunk, reserved);
+    let first = chunk.words().next().map(|(i, _)| i);
+    let last = chunk.words().last().map(|(i, _)| i);
+    chunk
         .segments
         .iter_mut()
-        .filter_map(|segment| match segment {
-            Segment::Word(word) => Some(word),
-            _ => None,
-        })
-        .peekable();
-    if let Some(word) = words.next() {
-        word.word = word.to_titlecase_lower_rest();
-    }
-    while let Some(word) = words.next() {
-        word.word = match words.peek().is_none() {
-            true => word.to_titlecase_lower_rest(),
-            false => match is_reserved(word) {
-                true => word.to_lowercase(),
-                false => word.to_titlecase_lower_rest(),
-            },
-        };
-    }
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if locked[i] {
+                    return;
+                }
+                let is_last = Some(i) == last;
+                word.word = if Some(i) == first || is_last {
+                    titlecase_compound(word, reserved, prefixes, trust_input, is_last)
+                } else {
+                    match phrases[i] || word.is_reserved(reserved) {
+                        true => word.to_lowercase(),
+                        false => titlecase_compound(word, reserved, prefixes, trust_input, false),
+                    }
+                };
+            }
+        });
     chunk.into()
 }
 
-fn titlecase_gruber(chunk: Chunk, opts: StyleOptions) -> String {
+// The first element of a hyphenated compound is always capitalized, later elements follow the
+// same rules as free standing words. Elements following a prefix that can't stand on its own are
+// not capitalized (CMOS 8.161) unless they are proper nouns or adjectives, which can only be told
+// from mixed case input already having them capitalized ("Non-English"). The final element of the
+// last word in a title is always capitalized ("Run-In").
+fn titlecase_compound(
+    word: &Word,
+    reserved: &ReservedWords,
+    prefixes: Option<&ReservedWords>,
+    trust_input: bool,
+    is_last: bool,
+) -> String {
+    let parts = word
+        .word
+        .split(['-', '\u{2010}', '\u{2013}'])
+        .filter(|part| !part.is_empty())
+        .count();
+    let mut after_prefix = false;
+    map_compound(&word.word, |i, part| {
+        let proper = trust_input
+            && part.chars().next().is_some_and(char::is_uppercase)
+            && part.chars().any(char::is_lowercase);
+        let final_element = is_last && i + 1 == parts;
+        let lower = i > 0
+            && !final_element
+            && ((after_prefix && !proper) || reserved.contains(part));
+        after_prefix = prefixes.is_some_and(|prefixes| prefixes.contains(part));
+        match lower {
+            true => part.to_lowercase(),
+            false => part.to_titlecase_lower_rest(),
+        }
+    })
+}
+
+fn titlecase_gruber(chunk: Chunk, opts: &StyleOptions) -> String {
     // The titlecase crate we are going to delegate to here trims the input. We need to restore
     // leading and trailing whitespace ourselves.
     let leading_trivia = if let Some(Segment::Separator(s)) = chunk.segments.first() {
@@ -66,26 +266,8 @@ fn titlecase_gruber(chunk: Chunk, opts: StyleOptions) -> String {
     let mut titilized = gruber_titlecase(chunk.to_string().as_ref());
     if opts.overrides.is_some() {
         let mut chunk: Chunk = titilized.into();
-        chunk.segments.iter_mut().for_each(|segment| {
-            if let Segment::Word(word) = segment {
-                word.word =
-                    if let Some(word) = get_override(word, &opts.overrides, |w| w.to_lowercase()) {
-                        word.to_string()
-                    } else {
-                        word.to_string()
-                    }
-            }
-        });
+        apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
         titilized = chunk.to_string();
     }
     format!("{}{}{}", leading_trivia, titilized, trailing_trivia)
 }
-
-fn is_reserved(word: &Word) -> bool {
-    let word = word.to_lowercase();
-    let word = word.as_str();
-    let article = Regex::new(r"^(a|an|the)$").unwrap();
-    let congunction = Regex::new(r"^(for|and|nor|but|or|yet|so|both|either|neither|not only|whether|after|although|as|as if|as long as|as much as|as soon as|as though|because|before|by the time|even if|even though|if|in order that|in case|in the event that|lest|now that|once|only|only if|provided that|since|so|supposing|that|than|though|till|unless|until|when|whenever|where|whereas|wherever|whether or not|while)$").unwrap();
-    let preposition = Regex::new(r"^(about|above|across|after|against|along|among|around|at|before|behind|between|beyond|but|by|concerning|despite|down|during|except|following|for|from|in|including|into|like|near|of|off|on|onto|out|over|past|plus|since|throughout|to|towards|under|until|up|upon|up|to|with|within|without)$").unwrap();
-    article.is_match(word) || congunction.is_match(word) || preposition.is_match(word)
-}
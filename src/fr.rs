@@ -0,0 +1,177 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{ReservedWords, apply_overrides, keep_acronyms, split_elisions};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub use crate::generics::{lowercase, sentencecase, uppercase};
+
+pub const STYLE_GUIDES: &[StyleGuide] =
+    &[StyleGuide::LanguageDefault, StyleGuide::ImprimerieNationale];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::ImprimerieNationale
+}
+
+static ELISIONS: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "c", "d", "j", "jusqu", "l", "lorsqu", "m", "n", "puisqu", "qu", "quoiqu", "s", "t",
+    ])
+});
+
+static DEFINITE_ARTICLES: LazyLock<ReservedWords> =
+    LazyLock::new(|| ReservedWords::from_slice(&["l'", "l’", "la", "le", "les"]));
+
+// Adjectives and numerals that usually come before the noun they modify and get capitalized along
+// with it
+static PREPOSED_ADJECTIVES: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "autre",
+        "autres",
+        "beau",
+        "beaux",
+        "bel",
+        "belle",
+        "belles",
+        "bon",
+        "bonne",
+        "bonnes",
+        "bons",
+        "cent",
+        "cents",
+        "cinq",
+        "cinquante",
+        "dernier",
+        "dernière",
+        "dernières",
+        "derniers",
+        "deux",
+        "dix",
+        "douze",
+        "grand",
+        "grande",
+        "grandes",
+        "grands",
+        "gros",
+        "grosse",
+        "grosses",
+        "haut",
+        "haute",
+        "hautes",
+        "hauts",
+        "huit",
+        "jeune",
+        "jeunes",
+        "joli",
+        "jolie",
+        "jolies",
+        "jolis",
+        "long",
+        "longs",
+        "longue",
+        "longues",
+        "mauvais",
+        "mauvaise",
+        "mauvaises",
+        "meilleur",
+        "meilleure",
+        "meilleures",
+        "meilleurs",
+        "mille",
+        "neuf",
+        "nouveau",
+        "nouveaux",
+        "nouvel",
+        "nouvelle",
+        "nouvelles",
+        "onze",
+        "petit",
+        "petite",
+        "petites",
+        "petits",
+        "premier",
+        "première",
+        "premières",
+        "premiers",
+        "quarante",
+        "quatorze",
+        "quatre",
+        "quinze",
+        "seize",
+        "sept",
+        "six",
+        "soixante",
+        "treize",
+        "trente",
+        "trois",
+        "vieil",
+        "vieille",
+        "vieilles",
+        "vieux",
+        "vingt",
+        "vingts",
+        "vrai",
+        "vraie",
+        "vraies",
+        "vrais",
+    ])
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => titlecase_imprimerie(chunk, opts),
+        StyleGuide::ImprimerieNationale => titlecase_imprimerie(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::FR,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+#[derive(PartialEq)]
+enum Position {
+    First,
+    AfterArticle,
+    Rest,
+}
+
+// Titles of works capitalize their first word, and when that is a definite article also the first
+// noun along with any adjectives before it: "Le Petit Prince", "L'Étranger", "La Condition
+// humaine".
+fn titlecase_imprimerie(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    split_elisions(&mut chunk, &ELISIONS, &mut locked);
+    let mut position = Position::First;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                let capitalize = position != Position::Rest;
+                position = match position {
+                    Position::First if DEFINITE_ARTICLES.contains(&word.word) => {
+                        Position::AfterArticle
+                    }
+                    Position::AfterArticle if PREPOSED_ADJECTIVES.contains(&word.word) => {
+                        Position::AfterArticle
+                    }
+                    _ => Position::Rest,
+                };
+                if locked[i] {
+                    return;
+                }
+                word.word = match capitalize {
+                    true => word.word.to_titlecase_lower_rest(),
+                    false => word.word.to_lowercase(),
+                };
+            }
+        });
+    chunk.into()
+}
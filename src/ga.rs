@@ -0,0 +1,270 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{ReservedWords, apply_overrides, is_acronym};
+use crate::types::{
+    Acronyms, Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu,
+};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[StyleGuide::LanguageDefault];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::LanguageDefault
+}
+
+// Articles, particles, common prepositions, and conjunctions
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "a", "ag", "agus", "an", "ar", "as", "de", "do", "faoi", "go", "i", "le", "leis", "na",
+        "nó", "ó", "sa", "san", "trí",
+    ])
+});
+
+// Words that put an h in front of a following vowel, which can't be told apart from a word that
+// really starts with an h by its spelling alone
+static H_PREFIXING: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&["a", "chomh", "go", "le", "na", "ná", "ó", "trí"])
+});
+
+// Vowel initial stems that commonly take an h-prefix. Lots of words really start with an h ("hata",
+// "halla"), so in lower case the h is only split off when what follows is known to be a word of its
+// own.
+const H_PREFIXED_STEMS: &[&str] = &[
+    "afraic",
+    "aimsir",
+    "áit",
+    "albain",
+    "árais",
+    "astráil",
+    "athai",
+    "athar",
+    "eagla",
+    "ealaín",
+    "éire",
+    "eolais",
+    "eolas",
+    "eora",
+    "eorp",
+    "iarth",
+    "iodáil",
+    "inse",
+    "ísiltír",
+    "oíche",
+    "oideachas",
+    "oifig",
+    "oileá",
+    "ollscoil",
+    "ospidéal",
+    "uair",
+    "uisce",
+    "ungáir",
+];
+
+// Words that put a t in front of a following s
+static T_PREFIXING: LazyLock<ReservedWords> =
+    LazyLock::new(|| ReservedWords::from_slice(&["an", "den", "don", "sa", "san"]));
+
+// Initial clusters that only come about through eclipsis or a t-prefix, along with the length of
+// the prefix part
+const PREFIXES: &[(&str, usize)] = &[
+    ("bhf", 2),
+    ("bp", 1),
+    ("dt", 1),
+    ("gc", 1),
+    ("mb", 1),
+    ("nd", 1),
+    ("ng", 1),
+    ("ts", 1),
+];
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => titlecase_irish(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::GA,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+fn titlecase_irish(chunk: Chunk, opts: &StyleOptions) -> String {
+    recase(chunk, opts, |first, prefix, radical| {
+        match !first && prefix.is_empty() && RESERVED.contains(radical) {
+            true => radical.to_lowercase(),
+            false => titlecase_word(prefix, radical),
+        }
+    })
+}
+
+pub fn lowercase(mut chunk: Chunk) -> String {
+    let previous = previous_words(&chunk);
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                let (prefix, radical) = split_mutation(&word.word, &previous[i]);
+                word.word = lowercase_word(prefix, radical);
+            }
+        });
+    chunk.into()
+}
+
+pub fn uppercase(mut chunk: Chunk) -> String {
+    let previous = previous_words(&chunk);
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                let (prefix, radical) = split_mutation(&word.word, &previous[i]);
+                word.word = format!("{}{}", prefix.to_lowercase(), radical.to_uppercase());
+            }
+        });
+    chunk.into()
+}
+
+pub fn sentencecase(chunk: Chunk, opts: &StyleOptions) -> String {
+    recase(chunk, opts, |first, prefix, radical| match first {
+        true => titlecase_word(prefix, radical),
+        false => lowercase_word(prefix, radical),
+    })
+}
+
+// The callback gets whether the word is the first one in the chunk, the mutation prefix (if any),
+// and the radical word it was prefixed to.
+fn recase<F>(mut chunk: Chunk, opts: &StyleOptions, case_fn: F) -> String
+where
+    F: Fn(bool, &str, &str) -> String,
+{
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    let previous = previous_words(&chunk);
+    keep_acronyms(&chunk, opts.acronyms, &previous, &mut locked);
+    let mut done_first = false;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if !locked[i] {
+                    let (prefix, radical) = split_mutation(&word.word, &previous[i]);
+                    word.word = case_fn(!done_first, prefix, radical);
+                }
+                done_first = true;
+            }
+        });
+    chunk.into()
+}
+
+// Prefixes never take a capital, which goes on the original initial instead: "i nGaillimh",
+// "an tAthair", "na hÉireann".
+fn titlecase_word(prefix: &str, radical: &str) -> String {
+    format!(
+        "{}{}",
+        prefix.to_lowercase(),
+        radical.to_titlecase_lower_rest()
+    )
+}
+
+// In lower case the n- and t-prefixes get hyphenated before vowels: "ár n-athair", "an t-uisce".
+fn lowercase_word(prefix: &str, radical: &str) -> String {
+    let prefix = prefix.to_lowercase();
+    let hyphen = matches!(prefix.as_str(), "n" | "t") && radical.starts_with(is_vowel);
+    match hyphen {
+        true => format!("{prefix}-{}", radical.to_lowercase()),
+        false => format!("{prefix}{}", radical.to_lowercase()),
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    c.to_lowercase()
+        .all(|c| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú'))
+}
+
+// Split a word into the prefix added by an initial mutation and the radical word, dropping the
+// hyphen between them if there is one. Words without a prefix come back with an empty one.
+fn split_mutation<'a>(word: &'a str, previous: &str) -> (&'a str, &'a str) {
+    let mut chars = word.char_indices();
+    let (Some((_, first)), Some((idx, second)), Some((_, third))) =
+        (chars.next(), chars.next(), chars.next())
+    else {
+        return ("", word);
+    };
+    let n_or_t = matches!(first.to_ascii_lowercase(), 'n' | 't');
+    if n_or_t && second == '-' && is_vowel(third) {
+        return (&word[..idx], &word[idx + 1..]);
+    }
+    if !is_vowel(second) {
+        let lower = word.to_lowercase();
+        let Some((prefix, radical)) = PREFIXES
+            .iter()
+            .find(|(cluster, _)| lower.starts_with(cluster))
+            .map(|(_, length)| word.split_at(*length))
+        else {
+            return ("", word);
+        };
+        // Only a lower case prefix can be one, and unless the radical has a capital to show where
+        // it starts the whole word has to be in lower case: "Mbappé" and "TSUNAMI" are not
+        // eclipsed. Even then the t-prefix needs an article to come after.
+        let capital = radical.chars().next().is_some_and(char::is_uppercase);
+        let lower = !word.chars().any(char::is_uppercase);
+        let t = prefix == "t" && !capital;
+        return match prefix.chars().all(char::is_lowercase)
+            && (capital || lower)
+            && (!t || T_PREFIXING.contains(previous))
+        {
+            true => (prefix, radical),
+            false => ("", word),
+        };
+    }
+    // Before vowels the prefix can only be told apart by its case, except for h where the word
+    // before it and a known stem after it can also give it away
+    let h = first.eq_ignore_ascii_case(&'h');
+    let cased = first.is_lowercase() && second.is_uppercase();
+    let stem = word[idx..].to_lowercase();
+    let known = H_PREFIXED_STEMS.iter().any(|s| stem.starts_with(s));
+    match (n_or_t || h) && cased || h && known && H_PREFIXING.contains(previous) {
+        true => word.split_at(idx),
+        false => ("", word),
+    }
+}
+
+// The word before each word in a chunk, in lower case, as some mutations depend on it
+fn previous_words(chunk: &Chunk) -> Vec<String> {
+    let mut words = vec![String::new(); chunk.segments.len()];
+    let mut previous = String::new();
+    for (idx, word) in chunk.words() {
+        words[idx] = std::mem::replace(&mut previous, word.word.to_lowercase());
+    }
+    words
+}
+
+// Like the generic acronym check, but looking past mutation prefixes so "nGaillimh" isn't mistaken
+// for intentional mixed case and "ÁR nATHAIR" counts as all capitals.
+fn keep_acronyms(chunk: &Chunk, acronyms: Acronyms, previous: &[String], locked: &mut [bool]) {
+    if acronyms != Acronyms::Keep {
+        return;
+    }
+    let radicals: Vec<(usize, &str)> = chunk
+        .words()
+        .map(|(idx, word)| (idx, split_mutation(&word.word, &previous[idx]).1))
+        .collect();
+    if radicals
+        .iter()
+        .all(|(_, radical)| !radical.chars().any(char::is_lowercase))
+    {
+        return;
+    }
+    radicals
+        .iter()
+        .filter(|(_, radical)| is_acronym(radical))
+        .for_each(|(idx, _)| locked[*idx] = true);
+}
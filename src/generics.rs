@@ -1,8 +1,10 @@
 // SPDX-FileCopyrightText: © 2023 Caleb Maclennan <caleb@alerque.com>
 // SPDX-License-Identifier: LGPL-3.0-only
 
-use crate::content::{Chunk, Segment};
-use crate::types::Word;
+use crate::content::{Chunk, Segment, word_core};
+use crate::types::{
+    Acronyms, Locale, Result, StyleGuide, StyleOptions, Subtitles, UnsupportedStyleGuideSnafu, Word,
+};
 
 use std::collections::HashSet;
 use unicode_titlecase::StrTitleCase;
@@ -10,24 +12,140 @@ use unicode_titlecase::StrTitleCase;
 #[derive(Clone, Debug)]
 pub struct ReservedWords {
     words: HashSet<String>,
+    longest_phrase: usize,
 }
 
 impl ReservedWords {
     pub fn from_slice(words: &[&str]) -> Self {
-        Self {
-            words: words.iter().map(|&s| s.to_lowercase()).collect(),
-        }
+        let mut reserved = Self {
+            words: HashSet::new(),
+            longest_phrase: 1,
+        };
+        reserved.add_slice(words);
+        reserved
     }
 
     pub fn add_slice(&mut self, words: &[&str]) {
         for &word in words {
-            self.words.insert(word.to_lowercase());
+            let word = word.to_lowercase();
+            let length = word.split_whitespace().count();
+            self.longest_phrase = self.longest_phrase.max(length);
+            self.words.insert(word);
         }
     }
 
     pub fn contains(&self, word: impl AsRef<str>) -> bool {
         self.words.contains(&word.as_ref().to_lowercase())
     }
+
+    /// Flag the segments of a chunk holding words that are part of a multi-word reserved phrase
+    pub fn match_phrases(&self, chunk: &Chunk) -> Vec<bool> {
+        let mut matched = vec![false; chunk.segments.len()];
+        let words: Vec<(usize, &Word)> = chunk.words().collect();
+        let mut i = 0;
+        while i < words.len() {
+            let longest = self.longest_phrase.min(words.len() - i);
+            let length = (2..=longest).rev().find(|&length| {
+                let candidate = &words[i..i + length];
+                if !candidate.windows(2).all(|w| chunk.adjacent(w[0].0, w[1].0)) {
+                    return false;
+                }
+                let phrase = candidate
+                    .iter()
+                    .map(|(_, word)| word.word.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                self.contains(phrase)
+            });
+            match length {
+                Some(length) => {
+                    words[i..i + length]
+                        .iter()
+                        .for_each(|(idx, _)| matched[*idx] = true);
+                    i += length;
+                }
+                None => i += 1,
+            }
+        }
+        matched
+    }
+}
+
+/// Put overrides in the shape [`apply_overrides`] expects them in
+///
+/// Punctuation around words is never part of them, so it is dropped along with any phrase left
+/// empty. Phrases are ordered longest first so that the longest one wins when more than one could
+/// match. This only needs doing once for a set of options, not for every chunk they apply to.
+pub fn prepare_overrides(overrides: &mut Option<Vec<Word>>) {
+    let Some(phrases) = overrides else {
+        return;
+    };
+    let mut prepared: Vec<Word> = phrases
+        .iter()
+        .filter_map(|phrase| {
+            let parts: Vec<&str> = phrase
+                .word
+                .split_whitespace()
+                .map(word_core)
+                .filter(|part| !part.is_empty())
+                .collect();
+            (!parts.is_empty()).then(|| Word::from(parts.join(" ")))
+        })
+        .collect();
+    prepared.sort_by_key(|phrase| std::cmp::Reverse(phrase.word.split(' ').count()));
+    *phrases = prepared;
+}
+
+/// Replace words and phrases in a chunk that match an override with the override's casing
+///
+/// The overrides must already have been through [`prepare_overrides`]. Returns a flag for each
+/// segment of the chunk marking the words that were overridden so that casing rules can leave them
+/// alone.
+pub fn apply_overrides<F>(chunk: &mut Chunk, overrides: &Option<Vec<Word>>, case_fn: F) -> Vec<bool>
+where
+    F: Fn(&str) -> String,
+{
+    let mut locked = vec![false; chunk.segments.len()];
+    let Some(overrides) = overrides else {
+        return locked;
+    };
+    // Folding depends on the locale, so it is the one part that can't be done up front
+    let phrases: Vec<(Vec<&str>, Vec<String>)> = overrides
+        .iter()
+        .map(|phrase| {
+            let parts: Vec<&str> = phrase.word.split(' ').collect();
+            let folded = parts.iter().map(|part| case_fn(part)).collect();
+            (parts, folded)
+        })
+        .collect();
+    let words: Vec<(usize, String)> = chunk
+        .words()
+        .map(|(idx, word)| (idx, case_fn(&word.word)))
+        .collect();
+    let mut i = 0;
+    while i < words.len() {
+        let found = phrases.iter().find(|(_, folded)| {
+            let Some(candidate) = words.get(i..i + folded.len()) else {
+                return false;
+            };
+            candidate.windows(2).all(|w| chunk.adjacent(w[0].0, w[1].0))
+                && folded
+                    .iter()
+                    .zip(candidate)
+                    .all(|(part, (_, word))| part == word)
+        });
+        match found {
+            Some((parts, _)) => {
+                for (part, (idx, _)) in parts.iter().zip(&words[i..]) {
+                    chunk.segments[*idx] = Segment::Word(Word::from(*part));
+                    locked[*idx] = true;
+                }
+                i += parts.len();
+            }
+            None => i += 1,
+        }
+    }
+    locked
 }
 
 pub trait IsReserved {
@@ -40,8 +158,290 @@ impl IsReserved for Word {
     }
 }
 
-pub fn lowercase(chunk: Chunk) -> String {
-    let mut chunk = chunk.clone();
+/// Check whether a word is written in all capitals or has capitals past its first letter
+pub fn is_acronym(word: &str) -> bool {
+    let mut letters = word
+        .chars()
+        .filter(|c| c.is_lowercase() || c.is_uppercase());
+    let Some(first) = letters.next() else {
+        return false;
+    };
+    let rest: Vec<char> = letters.collect();
+    if rest.is_empty() {
+        return false;
+    }
+    let all_caps = first.is_uppercase() && rest.iter().all(|c| c.is_uppercase());
+    let inner_caps = rest.iter().any(|c| c.is_uppercase()) && rest.iter().any(|c| c.is_lowercase());
+    all_caps || inner_caps
+}
+
+/// Check whether every word in a chunk that has letters is in all capitals
+///
+/// Shouted input carries no information about the intended case of individual words.
+pub fn is_shouting(chunk: &Chunk) -> bool {
+    chunk
+        .words()
+        .map(|(_, word)| word.word.as_str())
+        .filter(|word| word.chars().any(|c| c.is_lowercase() || c.is_uppercase()))
+        .all(|word| !word.chars().any(|c| c.is_lowercase()))
+}
+
+/// Check whether a chunk is in genuinely mixed case, so the capitals it has mean something
+///
+/// Text that is shouted or already capitalizes every word (besides reserved words) carries no
+/// information about which words need their capitals.
+pub fn is_mixed_case(chunk: &Chunk, reserved: &ReservedWords) -> bool {
+    !is_shouting(chunk)
+        && chunk.words().any(|(_, word)| {
+            word.word.chars().next().is_some_and(char::is_lowercase) && !word.is_reserved(reserved)
+        })
+}
+
+/// Flag words that look like acronyms or have intentional mixed case so casing rules leave them
+/// alone
+///
+/// If every word in the chunk is in all capitals there is no telling acronyms apart from the rest
+/// of the text, so nothing gets flagged.
+pub fn keep_acronyms(chunk: &Chunk, acronyms: Acronyms, locked: &mut [bool]) {
+    if acronyms != Acronyms::Keep {
+        return;
+    }
+    if is_shouting(chunk) {
+        return;
+    }
+    chunk
+        .words()
+        .filter(|(_, word)| is_acronym(&word.word))
+        .for_each(|(idx, _)| locked[idx] = true);
+}
+
+/// Split elided articles and other clitics (e.g. French "l'" or "d'") off the word they are
+/// attached to so that casing rules see them as separate words
+///
+/// The apostrophe stays with the elided part and nothing gets inserted between the two, so the
+/// chunk still renders the same. Locked words are left intact and the flags shift along with them.
+pub fn split_elisions(chunk: &mut Chunk, elisions: &ReservedWords, locked: &mut Vec<bool>) {
+    for i in (0..chunk.segments.len()).rev() {
+        let Segment::Word(word) = &chunk.segments[i] else {
+            continue;
+        };
+        if locked[i] {
+            continue;
+        }
+        let Some((idx, apostrophe)) = word
+            .word
+            .char_indices()
+            .find(|(_, c)| matches!(c, '\'' | '’'))
+        else {
+            continue;
+        };
+        let split = idx + apostrophe.len_utf8();
+        if split == word.word.len() || !elisions.contains(&word.word[..idx]) {
+            continue;
+        }
+        let rest = Word::from(&word.word[split..]);
+        let elided = Word::from(&word.word[..split]);
+        chunk.segments[i] = Segment::Word(elided);
+        chunk.segments.insert(i + 1, Segment::Word(rest));
+        locked.insert(i + 1, false);
+    }
+}
+
+/// Recase each element of a hyphenated compound word, keeping the hyphens or dashes as they were
+///
+/// The callback gets the position of the element in the compound and the element itself.
+pub fn map_compound<F>(word: &str, mut case_fn: F) -> String
+where
+    F: FnMut(usize, &str) -> String,
+{
+    let mut output = String::with_capacity(word.len());
+    let mut parts = 0;
+    let mut start = 0;
+    for (idx, delimiter) in word.match_indices(['-', '\u{2010}', '\u{2013}']) {
+        if idx > start {
+            output.push_str(&case_fn(parts, &word[start..idx]));
+            parts += 1;
+        }
+        output.push_str(delimiter);
+        start = idx + delimiter.len();
+    }
+    if start < word.len() {
+        output.push_str(&case_fn(parts, &word[start..]));
+    }
+    output
+}
+
+/// Mend digraphs that count as a single letter but were capitalized one letter at a time
+///
+/// A word like "LJubav" or "LLanelli" is in title case, not an acronym. The callback gets the first
+/// two letters of each element of a compound in lower case and tells whether they are a digraph.
+pub fn join_digraphs<F>(chunk: &mut Chunk, is_digraph: F)
+where
+    F: Fn(&str) -> bool,
+{
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = map_compound(&word.word, |_, part| join_digraph(part, &is_digraph));
+        }
+    });
+}
+
+fn join_digraph<F>(part: &str, is_digraph: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let mut chars = part.chars();
+    let (Some(first), Some(second), Some(third)) = (chars.next(), chars.next(), chars.next())
+    else {
+        return part.to_string();
+    };
+    let pair: String = [first, second]
+        .iter()
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+    if !is_digraph(&pair)
+        || !first.is_uppercase()
+        || !second.is_uppercase()
+        || !third.is_lowercase()
+    {
+        return part.to_string();
+    }
+    let mut output = String::with_capacity(part.len());
+    output.push(first);
+    output.extend(second.to_lowercase());
+    output.push_str(&part[first.len_utf8() + second.len_utf8()..]);
+    output
+}
+
+/// Title case capitalizing every word except reserved words that aren't the first one
+pub fn titlecase_major_words(
+    mut chunk: Chunk,
+    opts: &StyleOptions,
+    reserved: &ReservedWords,
+) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let phrases = reserved.match_phrases(&chunk);
+    let mut done_first = false;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if locked[i] {
+                    done_first = true;
+                } else if !done_first {
+                    done_first = true;
+                    word.word = word.to_titlecase_lower_rest();
+                } else {
+                    word.word = match phrases[i] || word.is_reserved(reserved) {
+                        true => word.word.to_lowercase(),
+                        false => word.word.to_titlecase_lower_rest(),
+                    };
+                }
+            }
+        });
+    chunk.into()
+}
+
+/// Title case capitalizing every word except reserved words, unless they start the title or a
+/// subtitle following a colon
+pub fn titlecase_major_words_with_subtitles(
+    mut chunk: Chunk,
+    opts: &StyleOptions,
+    reserved: &ReservedWords,
+) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let phrases = reserved.match_phrases(&chunk);
+    let mut starting = true;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| match segment {
+            Segment::Punctuation(punctuation) if punctuation.ends_with(':') => starting = true,
+            Segment::Word(word) => {
+                if !locked[i] {
+                    word.word = match !starting && (phrases[i] || word.is_reserved(reserved)) {
+                        true => word.word.to_lowercase(),
+                        false => word.word.to_titlecase_lower_rest(),
+                    };
+                }
+                starting = false;
+            }
+            _ => {}
+        });
+    chunk.into()
+}
+
+/// Title case for languages that style titles like sentences, capitalizing only the first word
+pub fn titlecase_sentence_style(chunk: Chunk, opts: &StyleOptions) -> String {
+    sentence_style(chunk, opts, false)
+}
+
+fn sentence_style(mut chunk: Chunk, opts: &StyleOptions, subtitles: bool) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let mut starting = true;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| match segment {
+            Segment::Punctuation(punctuation) if subtitles && punctuation.ends_with(':') => {
+                starting = true
+            }
+            Segment::Word(word) => {
+                if !locked[i] {
+                    word.word = match starting {
+                        true => word.word.to_titlecase_lower_rest(),
+                        false => word.word.to_lowercase(),
+                    };
+                }
+                starting = false;
+            }
+            _ => {}
+        });
+    chunk.into()
+}
+
+/// Style guides for languages that style titles like sentences unless asked for major words
+pub const SENTENCE_STYLE_GUIDES: &[StyleGuide] =
+    &[StyleGuide::LanguageDefault, StyleGuide::MajorWords];
+
+/// The closest of the sentence style guides to some other guide
+pub fn closest_sentence_style(style: StyleGuide) -> StyleGuide {
+    match style.is_sentence_style() {
+        true => StyleGuide::LanguageDefault,
+        false => StyleGuide::MajorWords,
+    }
+}
+
+/// Title case for languages that style titles like sentences, with the major words style available
+/// to capitalize everything except their reserved words
+///
+/// Subtitles following a colon are part of the same sentence unless the options ask for them to
+/// be capitalized.
+pub fn titlecase_sentence_or_major_words(
+    chunk: Chunk,
+    locale: Locale,
+    style: StyleGuide,
+    opts: &StyleOptions,
+    reserved: &ReservedWords,
+) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => {
+            let subtitles = opts.subtitles == Subtitles::Capitalize;
+            sentence_style(chunk, opts, subtitles)
+        }
+        StyleGuide::MajorWords => titlecase_major_words_with_subtitles(chunk, opts, reserved),
+        style => UnsupportedStyleGuideSnafu { locale, style }.fail()?,
+    })
+}
+
+pub fn lowercase(mut chunk: Chunk) -> String {
     chunk.segments.iter_mut().for_each(|segment| {
         if let Segment::Word(word) = segment {
             word.word = word.word.to_lowercase()
@@ -50,8 +450,7 @@ pub fn lowercase(chunk: Chunk) -> String {
     chunk.into()
 }
 
-pub fn sentencecase(chunk: Chunk) -> String {
-    let mut chunk = chunk.clone();
+pub fn sentencecase(mut chunk: Chunk) -> String {
     let mut done_first = false;
     chunk.segments.iter_mut().for_each(|segment| {
         if let Segment::Word(word) = segment {
@@ -66,8 +465,7 @@ pub fn sentencecase(chunk: Chunk) -> String {
     chunk.into()
 }
 
-pub fn uppercase(chunk: Chunk) -> String {
-    let mut chunk = chunk.clone();
+pub fn uppercase(mut chunk: Chunk) -> String {
     chunk.segments.iter_mut().for_each(|segment| {
         if let Segment::Word(word) = segment {
             word.word = word.to_uppercase()
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{
+    ReservedWords, apply_overrides, keep_acronyms, split_elisions, titlecase_sentence_style,
+};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub use crate::generics::{
+    closest_sentence_style as closest_style, lowercase, sentencecase, uppercase,
+};
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[
+    StyleGuide::LanguageDefault,
+    StyleGuide::MajorWords,
+    StyleGuide::CapitalizeAfterElision,
+];
+
+static ELISIONS: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "all", "bell", "c", "coll", "d", "dall", "dell", "l", "nell", "quell", "quest", "sant",
+        "sull", "un",
+    ])
+});
+
+// Articles, simple and articulated prepositions, and conjunctions. Elided forms are listed without
+// their apostrophe.
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "a", "agli", "ai", "al", "all", "alla", "alle", "allo", "col", "coi", "coll", "con", "d",
+        "da", "dagli", "dai", "dal", "dall", "dalla", "dalle", "dallo", "degli", "dei", "del",
+        "dell", "della", "delle", "dello", "di", "e", "ed", "fra", "gli", "i", "il", "in", "l",
+        "la", "le", "lo", "ma", "nè", "né", "negli", "nei", "nel", "nell", "nella", "nelle",
+        "nello", "o", "od", "per", "se", "su", "sugli", "sui", "sul", "sull", "sulla", "sulle",
+        "sullo", "tra", "un", "una", "uno",
+    ])
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        // Italian titles normally only capitalize the first word, an elided article or preposition
+        // counts as part of the word it is attached to: "L'amica geniale".
+        StyleGuide::LanguageDefault => titlecase_sentence_style(chunk, opts),
+        StyleGuide::MajorWords => titlecase_major_words(chunk, opts),
+        StyleGuide::CapitalizeAfterElision => titlecase_after_elision(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::IT,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+// When titles are capitalized throughout, elided articles and prepositions are split off and
+// treated like their full forms, so the title reads "L'Amica Geniale" and "Storia dell'Arte".
+fn titlecase_major_words(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    split_elisions(&mut chunk, &ELISIONS, &mut locked);
+    let phrases = RESERVED.match_phrases(&chunk);
+    let mut done_first = false;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if !locked[i] {
+                    let stem = word.word.trim_end_matches(['\'', '’']);
+                    word.word = match done_first && (phrases[i] || RESERVED.contains(stem)) {
+                        true => word.word.to_lowercase(),
+                        false => word.word.to_titlecase_lower_rest(),
+                    };
+                }
+                done_first = true;
+            }
+        });
+    chunk.into()
+}
+
+// Some publishers treat an elided article or preposition at the start of a title as a word of its
+// own, so the word after it gets capitalized too: "L'Amica geniale", "Dell'Arte della guerra".
+fn titlecase_after_elision(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    split_elisions(&mut chunk, &ELISIONS, &mut locked);
+    let mut capitalize = true;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                let elided = word
+                    .word
+                    .strip_suffix(['\'', '’'])
+                    .is_some_and(|stem| RESERVED.contains(stem));
+                if !locked[i] {
+                    word.word = match capitalize {
+                        true => word.word.to_titlecase_lower_rest(),
+                        false => word.word.to_lowercase(),
+                    };
+                }
+                capitalize = capitalize && elided;
+            }
+        });
+    chunk.into()
+}
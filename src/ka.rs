@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::apply_overrides;
+use crate::types::{
+    Locale, Mtavruli, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu,
+};
+
+pub use crate::generics::lowercase;
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[StyleGuide::LanguageDefault];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::LanguageDefault
+}
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => keep_case(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::KA,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+pub fn uppercase(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = match opts.mtavruli {
+                Mtavruli::Keep => word
+                    .word
+                    .chars()
+                    .flat_map(|c| match is_mkhedruli(c) {
+                        true => vec![c],
+                        false => c.to_uppercase().collect(),
+                    })
+                    .collect(),
+                _ => word.word.to_uppercase(),
+            }
+        }
+    });
+    chunk.into()
+}
+
+pub fn sentencecase(chunk: Chunk, opts: &StyleOptions) -> String {
+    keep_case(chunk, opts)
+}
+
+// Georgian has no title case, and Mtavruli is only ever used to set whole words in capitals, so
+// capitalizing the first letter of a word would be wrong. Titles and sentences are left as they
+// are except for overrides.
+fn keep_case(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    chunk.into()
+}
+
+// The Mkhedruli letters that have a Mtavruli capital
+fn is_mkhedruli(c: char) -> bool {
+    matches!(c, '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}')
+}
@@ -5,16 +5,24 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod content;
+mod decasifier;
 mod generics;
 mod traits;
 pub mod types;
 
 pub use content::Chunk;
+pub use decasifier::Decasifier;
 #[cfg(feature = "unstable-trait")]
 pub use traits::Decasify;
-pub use types::{Case, Locale, StyleGuide, StyleOptions, StyleOptionsBuilder, Word};
+pub use types::{
+    Acronyms, Case, Eszett, Locale, Mtavruli, StyleFallback, StyleGuide, StyleOptions,
+    StyleOptionsBuilder, Subtitles, Word,
+};
 pub use types::{Error, Result};
 
+use strum::IntoEnumIterator;
+use types::UnsupportedStyleGuideSnafu;
+
 #[cfg(feature = "cli")]
 #[doc(hidden)]
 pub mod cli;
@@ -31,9 +39,29 @@ pub mod python;
 #[doc(hidden)]
 pub mod wasm;
 
+mod az;
+mod bs;
+mod ca;
+mod cs;
+mod cy;
+mod de;
+mod el;
 mod en;
 mod es;
+mod fr;
+mod ga;
+mod hr;
+mod it;
+mod ka;
+mod lt;
+mod nl;
+mod pl;
+mod pt;
+mod ru;
+mod sk;
+mod sr;
 mod tr;
+mod uk;
 
 /// Convert a string to a specific case following typesetting conventions for a target locale
 pub fn case<TC, TL, TS, TO>(
@@ -53,17 +81,7 @@ where
     Error: From<TS::Error>,
     Error: From<TO::Error>,
 {
-    let chunk: Chunk = chunk.into();
-    let case: Case = case.try_into()?;
-    let locale: Locale = locale.try_into()?;
-    let style: StyleGuide = style.try_into()?;
-    let opts: StyleOptions = opts.try_into()?;
-    match case {
-        Case::Lower => lowercase(chunk, locale),
-        Case::Upper => uppercase(chunk, locale),
-        Case::Sentence => sentencecase(chunk, locale),
-        Case::Title => titlecase(chunk, locale, style, opts),
-    }
+    Decasifier::new(case, locale, style, opts)?.apply(chunk)
 }
 
 /// Convert a string to title case following typesetting conventions for a target locale
@@ -84,12 +102,44 @@ where
     let chunk: Chunk = chunk.into();
     let locale: Locale = locale.try_into()?;
     let style: StyleGuide = style.try_into()?;
-    let opts: StyleOptions = opts.try_into()?;
-    Ok(match locale {
+    let mut opts: StyleOptions = opts.try_into()?;
+    generics::prepare_overrides(&mut opts.overrides);
+    let style = resolve_style(locale, style, opts.fallback)?;
+    titlecase_resolved(chunk, locale, style, &opts)
+}
+
+fn titlecase_resolved(
+    chunk: Chunk,
+    locale: Locale,
+    style: StyleGuide,
+    opts: &StyleOptions,
+) -> Result<String> {
+    match locale {
+        Locale::DE => de::titlecase(chunk, style, opts),
         Locale::EN => en::titlecase(chunk, style, opts),
         Locale::ES => es::titlecase(chunk, style, opts),
+        Locale::FR => fr::titlecase(chunk, style, opts),
+        Locale::IT => it::titlecase(chunk, style, opts),
+        Locale::PT => pt::titlecase(chunk, style, opts),
+        Locale::NL => nl::titlecase(chunk, style, opts),
+        Locale::AZ => az::titlecase(chunk, style, opts),
+        Locale::EL => el::titlecase(chunk, style, opts),
+        Locale::CA => ca::titlecase(chunk, style, opts),
+        Locale::PL => pl::titlecase(chunk, style, opts),
+        Locale::CS => cs::titlecase(chunk, style, opts),
+        Locale::SK => sk::titlecase(chunk, style, opts),
+        Locale::SR => sr::titlecase(chunk, style, opts),
+        Locale::HR => hr::titlecase(chunk, style, opts),
+        Locale::BS => bs::titlecase(chunk, style, opts),
+        Locale::RU => ru::titlecase(chunk, style, opts),
+        Locale::UK => uk::titlecase(chunk, style, opts),
+        Locale::GA => ga::titlecase(chunk, style, opts),
+        Locale::CY => cy::titlecase(chunk, style, opts),
+        Locale::LT => lt::titlecase(chunk, style, opts),
+        Locale::KA => ka::titlecase(chunk, style, opts),
+        Locale::PTPT => pt::titlecase_european(chunk, style, opts),
         Locale::TR => tr::titlecase(chunk, style, opts),
-    })
+    }
 }
 
 /// Convert a string to lower case following typesetting conventions for a target locale
@@ -100,11 +150,40 @@ where
 {
     let chunk: Chunk = chunk.into();
     let locale: Locale = locale.try_into()?;
-    Ok(match locale {
+    Ok(lowercase_with_options(
+        chunk,
+        locale,
+        &StyleOptions::default(),
+    ))
+}
+
+fn lowercase_with_options(chunk: Chunk, locale: Locale, opts: &StyleOptions) -> String {
+    match locale {
+        Locale::DE => de::lowercase(chunk, opts),
         Locale::EN => en::lowercase(chunk),
         Locale::ES => es::lowercase(chunk),
+        Locale::FR => fr::lowercase(chunk),
+        Locale::IT => it::lowercase(chunk),
+        Locale::PT => pt::lowercase(chunk),
+        Locale::NL => nl::lowercase(chunk),
+        Locale::AZ => az::lowercase(chunk),
+        Locale::EL => el::lowercase(chunk),
+        Locale::CA => ca::lowercase(chunk),
+        Locale::PL => pl::lowercase(chunk),
+        Locale::CS => cs::lowercase(chunk),
+        Locale::SK => sk::lowercase(chunk),
+        Locale::SR => sr::lowercase(chunk),
+        Locale::HR => hr::lowercase(chunk),
+        Locale::BS => bs::lowercase(chunk),
+        Locale::RU => ru::lowercase(chunk),
+        Locale::UK => uk::lowercase(chunk),
+        Locale::GA => ga::lowercase(chunk),
+        Locale::CY => cy::lowercase(chunk),
+        Locale::LT => lt::lowercase(chunk),
+        Locale::KA => ka::lowercase(chunk),
+        Locale::PTPT => pt::lowercase(chunk),
         Locale::TR => tr::lowercase(chunk),
-    })
+    }
 }
 
 /// Convert a string to upper case following typesetting conventions for a target locale
@@ -115,14 +194,46 @@ where
 {
     let chunk: Chunk = chunk.into();
     let locale: Locale = locale.try_into()?;
-    Ok(match locale {
+    Ok(uppercase_with_options(
+        chunk,
+        locale,
+        &StyleOptions::default(),
+    ))
+}
+
+fn uppercase_with_options(chunk: Chunk, locale: Locale, opts: &StyleOptions) -> String {
+    match locale {
+        Locale::DE => de::uppercase(chunk, opts),
         Locale::EN => en::uppercase(chunk),
         Locale::ES => es::uppercase(chunk),
+        Locale::FR => fr::uppercase(chunk),
+        Locale::IT => it::uppercase(chunk),
+        Locale::PT => pt::uppercase(chunk),
+        Locale::NL => nl::uppercase(chunk),
+        Locale::AZ => az::uppercase(chunk),
+        Locale::EL => el::uppercase(chunk),
+        Locale::CA => ca::uppercase(chunk),
+        Locale::PL => pl::uppercase(chunk),
+        Locale::CS => cs::uppercase(chunk),
+        Locale::SK => sk::uppercase(chunk),
+        Locale::SR => sr::uppercase(chunk),
+        Locale::HR => hr::uppercase(chunk),
+        Locale::BS => bs::uppercase(chunk),
+        Locale::RU => ru::uppercase(chunk),
+        Locale::UK => uk::uppercase(chunk),
+        Locale::GA => ga::uppercase(chunk),
+        Locale::CY => cy::uppercase(chunk),
+        Locale::LT => lt::uppercase(chunk),
+        Locale::KA => ka::uppercase(chunk, opts),
+        Locale::PTPT => pt::uppercase(chunk),
         Locale::TR => tr::uppercase(chunk),
-    })
+    }
 }
 
 /// Convert a string to sentence case following typesetting conventions for a target locale
+///
+/// This always uses the default [`StyleOptions`]. To apply overrides or other options use [`case`]
+/// or a [`Decasifier`] with [`Case::Sentence`] instead.
 pub fn sentencecase<TL>(chunk: impl Into<Chunk>, locale: TL) -> Result<String>
 where
     TL: TryInto<Locale>,
@@ -130,22 +241,115 @@ where
 {
     let chunk: Chunk = chunk.into();
     let locale: Locale = locale.try_into()?;
-    Ok(match locale {
+    Ok(sentencecase_with_options(
+        chunk,
+        locale,
+        &StyleOptions::default(),
+    ))
+}
+
+fn sentencecase_with_options(chunk: Chunk, locale: Locale, opts: &StyleOptions) -> String {
+    match locale {
+        Locale::DE => de::sentencecase(chunk, opts),
         Locale::EN => en::sentencecase(chunk),
         Locale::ES => es::sentencecase(chunk),
+        Locale::FR => fr::sentencecase(chunk),
+        Locale::IT => it::sentencecase(chunk),
+        Locale::PT => pt::sentencecase(chunk),
+        Locale::NL => nl::sentencecase(chunk, opts),
+        Locale::AZ => az::sentencecase(chunk),
+        Locale::EL => el::sentencecase(chunk),
+        Locale::CA => ca::sentencecase(chunk),
+        Locale::PL => pl::sentencecase(chunk, opts),
+        Locale::CS => cs::sentencecase(chunk, opts),
+        Locale::SK => sk::sentencecase(chunk, opts),
+        Locale::SR => sr::sentencecase(chunk, opts),
+        Locale::HR => hr::sentencecase(chunk, opts),
+        Locale::BS => bs::sentencecase(chunk, opts),
+        Locale::RU => ru::sentencecase(chunk, opts),
+        Locale::UK => uk::sentencecase(chunk, opts),
+        Locale::GA => ga::sentencecase(chunk, opts),
+        Locale::CY => cy::sentencecase(chunk, opts),
+        Locale::LT => lt::sentencecase(chunk, opts),
+        Locale::KA => ka::sentencecase(chunk, opts),
+        Locale::PTPT => pt::sentencecase(chunk),
         Locale::TR => tr::sentencecase(chunk),
-    })
+    }
 }
 
-fn get_override<F>(word: &Word, overrides: &Option<Vec<Word>>, case_fn: F) -> Option<Word>
-where
-    F: Fn(&String) -> String,
-{
-    let word_lower = case_fn(&word.word);
-    overrides.as_ref().and_then(|words| {
-        words
-            .iter()
-            .find(|w| case_fn(&w.word) == word_lower)
-            .cloned()
-    })
+/// List the style guides available for title casing in a target locale
+pub fn supported_styles(locale: Locale) -> Vec<StyleGuide> {
+    style_guides(locale).to_vec()
+}
+
+/// List the locales that have an implementation of a target style guide
+pub fn supported_locales(style: StyleGuide) -> Vec<Locale> {
+    Locale::iter()
+        .filter(|locale| style_guides(*locale).contains(&style))
+        .collect()
+}
+
+fn style_guides(locale: Locale) -> &'static [StyleGuide] {
+    match locale {
+        Locale::DE => de::STYLE_GUIDES,
+        Locale::EN => en::STYLE_GUIDES,
+        Locale::ES => es::STYLE_GUIDES,
+        Locale::FR => fr::STYLE_GUIDES,
+        Locale::IT => it::STYLE_GUIDES,
+        Locale::PT => pt::STYLE_GUIDES,
+        Locale::NL => nl::STYLE_GUIDES,
+        Locale::AZ => az::STYLE_GUIDES,
+        Locale::EL => el::STYLE_GUIDES,
+        Locale::CA => ca::STYLE_GUIDES,
+        Locale::PL => pl::STYLE_GUIDES,
+        Locale::CS => cs::STYLE_GUIDES,
+        Locale::SK => sk::STYLE_GUIDES,
+        Locale::SR => sr::STYLE_GUIDES,
+        Locale::HR => hr::STYLE_GUIDES,
+        Locale::BS => bs::STYLE_GUIDES,
+        Locale::RU => ru::STYLE_GUIDES,
+        Locale::UK => uk::STYLE_GUIDES,
+        Locale::GA => ga::STYLE_GUIDES,
+        Locale::CY => cy::STYLE_GUIDES,
+        Locale::LT => lt::STYLE_GUIDES,
+        Locale::KA => ka::STYLE_GUIDES,
+        Locale::PTPT => pt::STYLE_GUIDES,
+        Locale::TR => tr::STYLE_GUIDES,
+    }
+}
+
+fn resolve_style(locale: Locale, style: StyleGuide, fallback: StyleFallback) -> Result<StyleGuide> {
+    if style_guides(locale).contains(&style) {
+        return Ok(style);
+    }
+    match fallback {
+        StyleFallback::Error => UnsupportedStyleGuideSnafu { locale, style }.fail(),
+        StyleFallback::LanguageDefault => Ok(StyleGuide::LanguageDefault),
+        StyleFallback::Closest => Ok(match locale {
+            Locale::DE => de::closest_style(style),
+            Locale::EN => en::closest_style(style),
+            Locale::ES => es::closest_style(style),
+            Locale::FR => fr::closest_style(style),
+            Locale::IT => it::closest_style(style),
+            Locale::PT => pt::closest_style(style),
+            Locale::NL => nl::closest_style(style),
+            Locale::AZ => az::closest_style(style),
+            Locale::EL => el::closest_style(style),
+            Locale::CA => ca::closest_style(style),
+            Locale::PL => pl::closest_style(style),
+            Locale::CS => cs::closest_style(style),
+            Locale::SK => sk::closest_style(style),
+            Locale::SR => sr::closest_style(style),
+            Locale::HR => hr::closest_style(style),
+            Locale::BS => bs::closest_style(style),
+            Locale::RU => ru::closest_style(style),
+            Locale::UK => uk::closest_style(style),
+            Locale::GA => ga::closest_style(style),
+            Locale::CY => cy::closest_style(style),
+            Locale::LT => lt::closest_style(style),
+            Locale::KA => ka::closest_style(style),
+            Locale::PTPT => pt::closest_style(style),
+            Locale::TR => tr::closest_style(style),
+        }),
+    }
 }
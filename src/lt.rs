@@ -0,0 +1,150 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{apply_overrides, keep_acronyms};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[StyleGuide::LanguageDefault];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::LanguageDefault
+}
+
+const DOT_ABOVE: char = '\u{0307}';
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => sentencecase(chunk, opts),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::LT,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+pub fn lowercase(mut chunk: Chunk) -> String {
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = lowercase_lithuanian(&word.word)
+        }
+    });
+    chunk.into()
+}
+
+pub fn uppercase(mut chunk: Chunk) -> String {
+    chunk.segments.iter_mut().for_each(|segment| {
+        if let Segment::Word(word) = segment {
+            word.word = uppercase_lithuanian(&word.word)
+        }
+    });
+    chunk.into()
+}
+
+// Lithuanian titles are styled like sentences
+pub fn sentencecase(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, lowercase_lithuanian);
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let mut done_first = false;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if !locked[i] {
+                    word.word = match done_first {
+                        true => lowercase_lithuanian(&word.word),
+                        false => titlecase_lithuanian(&word.word),
+                    };
+                }
+                done_first = true;
+            }
+        });
+    chunk.into()
+}
+
+// Combining marks with the Above (230) canonical combining class
+fn is_above(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{0314}'
+        | '\u{033D}'..='\u{0344}'
+        | '\u{0346}'
+        | '\u{034A}'..='\u{034C}'
+        | '\u{0350}'..='\u{0352}'
+        | '\u{0357}'
+        | '\u{035B}'
+        | '\u{0363}'..='\u{036F}')
+}
+
+fn is_combining(c: char) -> bool {
+    matches!(c, '\u{0300}'..='\u{036F}')
+}
+
+fn is_soft_dotted(c: char) -> bool {
+    matches!(c, 'i' | 'j' | 'į' | 'ɨ' | 'ʝ')
+}
+
+// A capital I, J, or Į keeps its dot when lowercased if an accent goes on top of it, and the
+// precomposed accented capitals do too. Without an accent there is no extra dot: "ĮÌ" becomes
+// "įi̇̀".
+pub fn lowercase_lithuanian(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut output = String::with_capacity(s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            'Ì' => output.push_str("i\u{0307}\u{0300}"),
+            'Í' => output.push_str("i\u{0307}\u{0301}"),
+            'Ĩ' => output.push_str("i\u{0307}\u{0303}"),
+            'I' | 'J' | 'Į' if more_above(&chars[i + 1..]) => {
+                output.extend(c.to_lowercase());
+                output.push(DOT_ABOVE);
+            }
+            c => output.extend(c.to_lowercase()),
+        }
+    }
+    output
+}
+
+// Any dot above kept on a soft dotted letter is dropped again when uppercasing
+pub fn uppercase_lithuanian(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut output = String::with_capacity(s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c == DOT_ABOVE && after_soft_dotted(&chars[..i]) {
+            continue;
+        }
+        output.extend(c.to_uppercase());
+    }
+    output
+}
+
+fn titlecase_lithuanian(word: &str) -> String {
+    let split = word
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| !is_combining(*c))
+        .map_or(word.len(), |(idx, _)| idx);
+    let (first, rest) = word.split_at(split);
+    format!(
+        "{}{}",
+        uppercase_lithuanian(first),
+        lowercase_lithuanian(rest)
+    )
+}
+
+fn more_above(following: &[char]) -> bool {
+    following
+        .iter()
+        .take_while(|c| is_combining(**c))
+        .any(|c| is_above(*c))
+}
+
+fn after_soft_dotted(preceding: &[char]) -> bool {
+    preceding
+        .iter()
+        .rev()
+        .find(|c| !is_combining(**c) || is_above(**c))
+        .is_some_and(|c| is_soft_dotted(*c))
+}
@@ -18,7 +18,17 @@ macro_rules! impl_from_luaresult {
     };
 }
 
-impl_from_luaresult!(Locale, Case, StyleGuide, StyleOptions);
+impl_from_luaresult!(
+    Locale,
+    Case,
+    StyleGuide,
+    StyleFallback,
+    Acronyms,
+    Eszett,
+    Mtavruli,
+    Subtitles,
+    StyleOptions
+);
 
 impl IntoLua for Error {
     fn into_lua(self, _: &Lua) -> LuaResult<LuaValue> {
@@ -53,6 +63,41 @@ impl TryFrom<LuaString> for StyleGuide {
     }
 }
 
+impl TryFrom<LuaString> for StyleFallback {
+    type Error = Error;
+    fn try_from(s: LuaString) -> Result<Self> {
+        s.to_string_lossy().try_into()
+    }
+}
+
+impl TryFrom<LuaString> for Acronyms {
+    type Error = Error;
+    fn try_from(s: LuaString) -> Result<Self> {
+        s.to_string_lossy().try_into()
+    }
+}
+
+impl TryFrom<LuaString> for Eszett {
+    type Error = Error;
+    fn try_from(s: LuaString) -> Result<Self> {
+        s.to_string_lossy().try_into()
+    }
+}
+
+impl TryFrom<LuaString> for Mtavruli {
+    type Error = Error;
+    fn try_from(s: LuaString) -> Result<Self> {
+        s.to_string_lossy().try_into()
+    }
+}
+
+impl TryFrom<LuaString> for Subtitles {
+    type Error = Error;
+    fn try_from(s: LuaString) -> Result<Self> {
+        s.to_string_lossy().try_into()
+    }
+}
+
 #[mlua::lua_module]
 fn decasify(lua: &Lua) -> LuaResult<LuaTable> {
     let exports = lua.create_table()?;
@@ -72,10 +117,29 @@ fn decasify(lua: &Lua) -> LuaResult<LuaTable> {
         "uppercase",
         LuaFunction::wrap_raw::<_, (Chunk, Locale)>(uppercase),
     )?;
+    // Like the Rust function this uses the default options, case() is needed to apply overrides
     exports.set(
         "sentencecase",
         LuaFunction::wrap_raw::<_, (Chunk, Locale)>(sentencecase),
     )?;
+    exports.set(
+        "supported_styles",
+        lua.create_function(|_, locale: Locale| {
+            Ok(supported_styles(locale)
+                .iter()
+                .map(|style| style.to_string())
+                .collect::<Vec<_>>())
+        })?,
+    )?;
+    exports.set(
+        "supported_locales",
+        lua.create_function(|_, style: StyleGuide| {
+            Ok(supported_locales(style)
+                .iter()
+                .map(|locale| locale.to_string())
+                .collect::<Vec<_>>())
+        })?,
+    )?;
     let mt = lua.create_table()?;
     let decasify = lua.create_function(
         move |_,
@@ -167,6 +231,21 @@ impl FromLua for StyleOptions {
                         .collect();
                     builder = builder.overrides(overrides);
                 }
+                if let Ok(fallback) = t.get::<LuaString>("fallback") {
+                    builder = builder.fallback(fallback.try_into()?);
+                }
+                if let Ok(acronyms) = t.get::<LuaString>("acronyms") {
+                    builder = builder.acronyms(acronyms.try_into()?);
+                }
+                if let Ok(eszett) = t.get::<LuaString>("eszett") {
+                    builder = builder.eszett(eszett.try_into()?);
+                }
+                if let Ok(mtavruli) = t.get::<LuaString>("mtavruli") {
+                    builder = builder.mtavruli(mtavruli.try_into()?);
+                }
+                if let Ok(subtitles) = t.get::<LuaString>("subtitles") {
+                    builder = builder.subtitles(subtitles.try_into()?);
+                }
                 builder.build()
             }
             LuaValue::Nil => Self::default(),
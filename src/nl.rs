@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{ReservedWords, apply_overrides, keep_acronyms};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub use crate::generics::{closest_sentence_style as closest_style, lowercase, uppercase};
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[StyleGuide::LanguageDefault, StyleGuide::MajorWords];
+
+// Articles, prepositions, conjunctions, and the particles in names like "van der" or "ter". The
+// contracted articles 't and 'n are listed without their apostrophe since it is split off as
+// punctuation.
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "aan", "als", "bij", "de", "den", "der", "door", "een", "en", "het", "in", "met", "n",
+        "naar", "of", "om", "onder", "op", "over", "t", "te", "ten", "ter", "tot", "uit", "van",
+        "voor",
+    ])
+});
+
+// Contracted articles that can start a sentence without taking the capital themselves
+static CONTRACTIONS: LazyLock<ReservedWords> =
+    LazyLock::new(|| ReservedWords::from_slice(&["n", "s", "t"]));
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault => recase(chunk, opts, None),
+        StyleGuide::MajorWords => recase(chunk, opts, Some(&RESERVED)),
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::NL,
+            style,
+        }
+        .fail()?,
+    })
+}
+
+pub fn sentencecase(chunk: Chunk, opts: &StyleOptions) -> String {
+    recase(chunk, opts, None)
+}
+
+// Dutch titles are normally styled like sentences. When capitalizing a sentence or title that
+// starts with a contraction like 's or 't the capital goes on the next word instead ("'s Morgens"),
+// and in names like "'s-Hertogenbosch" the part after the contraction is always capitalized.
+fn recase(mut chunk: Chunk, opts: &StyleOptions, reserved: Option<&ReservedWords>) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let phrases = reserved.map(|reserved| reserved.match_phrases(&chunk));
+    let mut done_first = false;
+    for i in 0..chunk.segments.len() {
+        let contracted = i > 0
+            && matches!(&chunk.segments[i - 1], Segment::Punctuation(p) if p.ends_with(['\'', '’']));
+        let Segment::Word(word) = &mut chunk.segments[i] else {
+            continue;
+        };
+        if locked[i] {
+            done_first = true;
+            continue;
+        }
+        if contracted && CONTRACTIONS.contains(&word.word) {
+            word.word = word.word.to_lowercase();
+            continue;
+        }
+        let named = match word.word.split_once('-') {
+            Some((contraction, name)) if contracted && CONTRACTIONS.contains(contraction) => {
+                Some(format!(
+                    "{}-{}",
+                    contraction.to_lowercase(),
+                    to_titlecase_dutch(name)
+                ))
+            }
+            _ => None,
+        };
+        if let Some(named) = named {
+            word.word = named;
+            done_first = true;
+            continue;
+        }
+        let capitalize = match (reserved, &phrases) {
+            _ if !done_first => true,
+            (Some(reserved), Some(phrases)) => !phrases[i] && !reserved.contains(&word.word),
+            _ => false,
+        };
+        word.word = match capitalize {
+            true => to_titlecase_dutch(&word.word),
+            false => word.word.to_lowercase(),
+        };
+        done_first = true;
+    }
+    chunk.into()
+}
+
+// The IJ digraph counts as a single letter, so both parts get capitalized: "IJsland", "IJzer".
+fn to_titlecase_dutch(word: &str) -> String {
+    let lower = word.to_lowercase();
+    match lower
+        .strip_prefix("ij")
+        .or_else(|| lower.strip_prefix("íj"))
+    {
+        Some(rest) => {
+            let digraph: String = lower[..lower.len() - rest.len()].to_uppercase();
+            format!("{digraph}{rest}")
+        }
+        None => word.to_titlecase_lower_rest(),
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::Chunk;
+use crate::generics::{ReservedWords, titlecase_sentence_or_major_words};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions};
+
+use std::sync::LazyLock;
+
+pub use crate::generics::{
+    SENTENCE_STYLE_GUIDES as STYLE_GUIDES, closest_sentence_style as closest_style, lowercase,
+    titlecase_sentence_style as sentencecase, uppercase,
+};
+
+// Prepositions and conjunctions, including all the single letter ones
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "a", "aby", "ale", "albo", "bez", "czy", "dla", "do", "i", "lub", "na", "nad", "o", "od",
+        "oraz", "po", "pod", "przed", "przez", "przy", "u", "w", "we", "z", "za", "ze",
+    ])
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    titlecase_sentence_or_major_words(chunk, Locale::PL, style, opts, &RESERVED)
+}
@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::{Chunk, Segment};
+use crate::generics::{
+    ReservedWords, apply_overrides, keep_acronyms, map_compound, titlecase_sentence_style,
+};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu};
+
+use std::sync::LazyLock;
+use unicode_titlecase::StrTitleCase;
+
+pub use crate::generics::{lowercase, sentencecase, uppercase};
+
+pub const STYLE_GUIDES: &[StyleGuide] = &[
+    StyleGuide::LanguageDefault,
+    StyleGuide::AssociacaoBrasileiraDeNormasTecnicas,
+    StyleGuide::AcademiaDasCienciasDeLisboa,
+];
+
+pub fn closest_style(style: StyleGuide) -> StyleGuide {
+    match style.is_sentence_style() {
+        true => StyleGuide::AssociacaoBrasileiraDeNormasTecnicas,
+        false => StyleGuide::AcademiaDasCienciasDeLisboa,
+    }
+}
+
+// Articles, prepositions, their contractions, and conjunctions
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "a", "à", "ao", "aos", "as", "às", "com", "da", "das", "de", "do", "dos", "dum", "duma",
+        "dumas", "duns", "e", "em", "na", "nas", "nem", "no", "nos", "num", "numa", "numas",
+        "nuns", "o", "os", "ou", "para", "pela", "pelas", "pelo", "pelos", "per", "por", "sem",
+        "sob", "um", "uma", "umas", "uns",
+    ])
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    titlecase_regional(chunk, style, opts, Locale::PT)
+}
+
+// European Portuguese shares the casing rules and style guides, but defaults to its own style
+pub fn titlecase_european(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    titlecase_regional(chunk, style, opts, Locale::PTPT)
+}
+
+fn titlecase_regional(
+    chunk: Chunk,
+    style: StyleGuide,
+    opts: &StyleOptions,
+    locale: Locale,
+) -> Result<String> {
+    Ok(match style {
+        StyleGuide::LanguageDefault if locale == Locale::PTPT => titlecase_lisboa(chunk, opts),
+        // Brazilian practice (ABNT) styles titles of works like sentences, so months and words
+        // following a colon stay lowercase like any other common noun.
+        StyleGuide::LanguageDefault => titlecase_sentence_style(chunk, opts),
+        StyleGuide::AssociacaoBrasileiraDeNormasTecnicas => titlecase_sentence_style(chunk, opts),
+        StyleGuide::AcademiaDasCienciasDeLisboa => titlecase_lisboa(chunk, opts),
+        style => UnsupportedStyleGuideSnafu { locale, style }.fail()?,
+    })
+}
+
+// European practice capitalizes all but the reserved words in titles of works, including the
+// elements of hyphenated compounds ("Guarda-Chuva", "Pé-de-Meia"). A colon starts a subtitle that
+// is capitalized like the title itself.
+fn titlecase_lisboa(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
+    let phrases = RESERVED.match_phrases(&chunk);
+    let mut starting = true;
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| match segment {
+            Segment::Punctuation(punctuation) if punctuation.ends_with(':') => starting = true,
+            Segment::Word(word) => {
+                if !locked[i] {
+                    word.word = map_compound(&word.word, |part_idx, part| {
+                        let leading = starting && part_idx == 0;
+                        match !leading && (phrases[i] || RESERVED.contains(part)) {
+                            true => part.to_lowercase(),
+                            false => part.to_titlecase_lower_rest(),
+                        }
+                    });
+                }
+                starting = false;
+            }
+            _ => {}
+        });
+    chunk.into()
+}
@@ -15,44 +15,68 @@ fn decasify(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<Case>()?;
     module.add_class::<Locale>()?;
     module.add_class::<StyleGuide>()?;
+    module.add_class::<StyleFallback>()?;
+    module.add_class::<Acronyms>()?;
+    module.add_class::<Eszett>()?;
+    module.add_class::<Mtavruli>()?;
+    module.add_class::<Subtitles>()?;
     module.add_class::<StyleOptions>()?;
     module.add_function(wrap_pyfunction!(self::case, module)?)?;
     module.add_function(wrap_pyfunction!(self::titlecase, module)?)?;
     module.add_function(wrap_pyfunction!(self::lowercase, module)?)?;
     module.add_function(wrap_pyfunction!(self::uppercase, module)?)?;
     module.add_function(wrap_pyfunction!(self::sentencecase, module)?)?;
+    module.add_function(wrap_pyfunction!(self::supported_styles, module)?)?;
+    module.add_function(wrap_pyfunction!(self::supported_locales, module)?)?;
     let version = option_env!("VERGEN_GIT_DESCRIBE").unwrap_or_else(|| env!("CARGO_PKG_VERSION"));
     module.add::<&str, &str>("version", version)?;
     Ok(())
 }
 
 #[pyfunction]
-#[pyo3(signature = (input, case, locale, style=StyleGuide::LanguageDefault, overrides=None))]
+#[pyo3(signature = (input, case, locale, style=StyleGuide::LanguageDefault, overrides=None, fallback=StyleFallback::Error, acronyms=Acronyms::Recase, eszett=Eszett::Double, mtavruli=Mtavruli::Capital, subtitles=Subtitles::Lower))]
+#[allow(clippy::too_many_arguments)]
 fn case(
     input: String,
     case: Case,
     locale: Locale,
     style: StyleGuide,
     overrides: Option<Vec<String>>,
+    fallback: StyleFallback,
+    acronyms: Acronyms,
+    eszett: Eszett,
+    mtavruli: Mtavruli,
+    subtitles: Subtitles,
 ) -> PyResult<String> {
+    let builder = StyleOptionsBuilder::new()
+        .fallback(fallback)
+        .acronyms(acronyms)
+        .eszett(eszett)
+        .mtavruli(mtavruli)
+        .subtitles(subtitles);
     let opts = match overrides {
-        Some(words) => StyleOptionsBuilder::new().overrides(words).build(),
-        None => StyleOptions::default(),
+        Some(words) => builder.overrides(words).build(),
+        None => builder.build(),
     };
     Ok(crate::case(&input, case, locale, style, opts)?)
 }
 
 #[pyfunction]
-#[pyo3(signature = (input, locale, style=StyleGuide::LanguageDefault, overrides=None))]
+#[pyo3(signature = (input, locale, style=StyleGuide::LanguageDefault, overrides=None, fallback=StyleFallback::Error, acronyms=Acronyms::Recase))]
 fn titlecase(
     input: String,
     locale: Locale,
     style: StyleGuide,
     overrides: Option<Vec<String>>,
+    fallback: StyleFallback,
+    acronyms: Acronyms,
 ) -> PyResult<String> {
+    let builder = StyleOptionsBuilder::new()
+        .fallback(fallback)
+        .acronyms(acronyms);
     let opts = match overrides {
-        Some(words) => StyleOptionsBuilder::new().overrides(words).build(),
-        None => StyleOptions::default(),
+        Some(words) => builder.overrides(words).build(),
+        None => builder.build(),
     };
     Ok(crate::titlecase(&input, locale, style, opts)?)
 }
@@ -69,8 +93,21 @@ fn uppercase(input: String, locale: Locale) -> PyResult<String> {
     Ok(crate::uppercase(&input, locale)?)
 }
 
+/// Sentence case with the default options, use `case` to apply overrides or other options
 #[pyfunction]
 #[pyo3(signature = (input, locale))]
 fn sentencecase(input: String, locale: Locale) -> PyResult<String> {
     Ok(crate::sentencecase(&input, locale)?)
 }
+
+#[pyfunction]
+#[pyo3(signature = (locale))]
+fn supported_styles(locale: Locale) -> Vec<StyleGuide> {
+    crate::supported_styles(locale)
+}
+
+#[pyfunction]
+#[pyo3(signature = (style=StyleGuide::LanguageDefault))]
+fn supported_locales(style: StyleGuide) -> Vec<Locale> {
+    crate::supported_locales(style)
+}
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::Chunk;
+use crate::generics::{ReservedWords, titlecase_sentence_or_major_words};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions};
+
+use std::sync::LazyLock;
+
+pub use crate::generics::{
+    SENTENCE_STYLE_GUIDES as STYLE_GUIDES, closest_sentence_style as closest_style, lowercase,
+    titlecase_sentence_style as sentencecase, uppercase,
+};
+
+// Prepositions, conjunctions, and particles
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "а",
+        "без",
+        "в",
+        "во",
+        "да",
+        "для",
+        "до",
+        "же",
+        "за",
+        "и",
+        "из",
+        "или",
+        "к",
+        "ко",
+        "ли",
+        "на",
+        "над",
+        "не",
+        "ни",
+        "но",
+        "о",
+        "об",
+        "обо",
+        "от",
+        "по",
+        "под",
+        "при",
+        "про",
+        "с",
+        "со",
+        "у",
+        "через",
+    ])
+});
+
+// Titles follow GOST bibliographic practice and are styled like sentences, including any subtitle
+// following a colon unless asked to capitalize it. Proper nouns only keep their capitals through
+// overrides.
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    titlecase_sentence_or_major_words(chunk, Locale::RU, style, opts, &RESERVED)
+}
@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::Chunk;
+use crate::generics::{
+    ReservedWords, join_digraphs, titlecase_sentence_or_major_words, titlecase_sentence_style,
+};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions};
+
+use std::sync::LazyLock;
+
+pub use crate::generics::{
+    SENTENCE_STYLE_GUIDES as STYLE_GUIDES, closest_sentence_style as closest_style, lowercase,
+    uppercase,
+};
+
+// Prepositions and conjunctions, including all the single letter ones. Serbian is written in both
+// scripts so each of them is also reserved in Cyrillic.
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    let latin = [
+        "a", "ali", "bez", "do", "i", "ili", "iz", "k", "ka", "kod", "kroz", "na", "nad", "ni",
+        "niti", "o", "od", "pa", "po", "pod", "pred", "preko", "pri", "s", "sa", "te", "u", "uz",
+        "za",
+    ];
+    let mut reserved = ReservedWords::from_slice(&latin);
+    for word in latin {
+        reserved.add_slice(&[&to_cyrillic(word)]);
+    }
+    reserved
+});
+
+// The digraphs are single letters of the alphabet and the Cyrillic script has a letter for each
+const DIGRAPHS: &[(&str, char)] = &[("dž", 'џ'), ("lj", 'љ'), ("nj", 'њ')];
+
+const LETTERS: &[(char, char)] = &[
+    ('a', 'а'),
+    ('b', 'б'),
+    ('c', 'ц'),
+    ('č', 'ч'),
+    ('ć', 'ћ'),
+    ('d', 'д'),
+    ('đ', 'ђ'),
+    ('e', 'е'),
+    ('f', 'ф'),
+    ('g', 'г'),
+    ('h', 'х'),
+    ('i', 'и'),
+    ('j', 'ј'),
+    ('k', 'к'),
+    ('l', 'л'),
+    ('m', 'м'),
+    ('n', 'н'),
+    ('o', 'о'),
+    ('p', 'п'),
+    ('r', 'р'),
+    ('s', 'с'),
+    ('š', 'ш'),
+    ('t', 'т'),
+    ('u', 'у'),
+    ('v', 'в'),
+    ('z', 'з'),
+    ('ž', 'ж'),
+];
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    titlecase_regional(chunk, style, opts, Locale::SR)
+}
+
+pub fn sentencecase(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    join_digraphs(&mut chunk, is_digraph);
+    titlecase_sentence_style(chunk, opts)
+}
+
+// Croatian and Bosnian share the alphabet, digraphs, and reserved words
+pub fn titlecase_regional(
+    mut chunk: Chunk,
+    style: StyleGuide,
+    opts: &StyleOptions,
+    locale: Locale,
+) -> Result<String> {
+    join_digraphs(&mut chunk, is_digraph);
+    titlecase_sentence_or_major_words(chunk, locale, style, opts, &RESERVED)
+}
+
+fn is_digraph(pair: &str) -> bool {
+    DIGRAPHS.iter().any(|(digraph, _)| *digraph == pair)
+}
+
+fn to_cyrillic(word: &str) -> String {
+    let mut output = String::with_capacity(word.len() * 2);
+    let mut rest = word;
+    while let Some(c) = rest.chars().next() {
+        if let Some((digraph, letter)) = DIGRAPHS.iter().find(|(d, _)| rest.starts_with(d)) {
+            output.push(*letter);
+            rest = &rest[digraph.len()..];
+            continue;
+        }
+        let letter = LETTERS
+            .iter()
+            .find(|(l, _)| *l == c)
+            .map_or(c, |(_, cyr)| *cyr);
+        output.push(letter);
+        rest = &rest[c.len_utf8()..];
+    }
+    output
+}
@@ -2,56 +2,76 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 use crate::content::{Chunk, Segment};
-use crate::get_override;
-use crate::types::{StyleGuide, StyleOptions, Word};
+use crate::generics::{apply_overrides, keep_acronyms};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions, UnsupportedStyleGuideSnafu, Word};
 
 use regex::Regex;
+use std::sync::LazyLock;
 use unicode_titlecase::tr_az::StrTrAzCasing;
 use unicode_titlecase::StrTitleCase;
 
-pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: StyleOptions) -> String {
-    match style {
+pub const STYLE_GUIDES: &[StyleGuide] = &[
+    StyleGuide::LanguageDefault,
+    StyleGuide::TurkishLanguageInstitute,
+];
+
+pub fn closest_style(_style: StyleGuide) -> StyleGuide {
+    StyleGuide::TurkishLanguageInstitute
+}
+
+static BAGLAC: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([Vv][Ee]|[İi][Ll][Ee]|[Yy][Aa]|[Yy][Aa][Hh][Uu][Tt]|[Kk][İi]|[Dd][AaEe])$")
+        .unwrap()
+});
+
+static SORUEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([Mm][İiIıUuÜü])([Dd][İiIıUuÜü][Rr]([Ll][AaEe][Rr])?|[Ss][İiIıUuÜü][Nn]|[Yy][İiIıUuÜü][Zz]|[Ss][İiIıUuÜü][Nn][İiIıUuÜü][Zz]|[Ll][AaEe][Rr])?$").unwrap()
+});
+
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    Ok(match style {
         StyleGuide::LanguageDefault => titlecase_tdk(chunk, opts),
         StyleGuide::TurkishLanguageInstitute => titlecase_tdk(chunk, opts),
-        _ => todo!("Turkish implementation doesn't support different style guides."),
-    }
+        style => UnsupportedStyleGuideSnafu {
+            locale: Locale::TR,
+            style,
+        }
+        .fail()?,
+    })
 }
 
-fn titlecase_tdk(chunk: Chunk, opts: StyleOptions) -> String {
-    let mut chunk = chunk.clone();
+fn titlecase_tdk(mut chunk: Chunk, opts: &StyleOptions) -> String {
+    let mut locked = apply_overrides(&mut chunk, &opts.overrides, |w| w.to_lowercase_tr_az());
+    keep_acronyms(&chunk, opts.acronyms, &mut locked);
     let mut done_first = false;
-    chunk.segments.iter_mut().for_each(|segment| {
-        if let Segment::Word(word) = segment {
-            word.word = if let Some(word) =
-                get_override(word, &opts.overrides, |w| w.to_lowercase_tr_az())
-            {
-                word.to_string()
-            } else if !done_first {
-                done_first = true;
-                word.to_titlecase_tr_or_az_lower_rest()
-            } else {
-                match is_reserved(word) {
-                    true => word.word.to_lowercase_tr_az(),
-                    false => word.word.to_titlecase_tr_or_az_lower_rest(),
+    chunk
+        .segments
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, segment)| {
+            if let Segment::Word(word) = segment {
+                if locked[i] {
+                    done_first = true;
+                } else if !done_first {
+                    done_first = true;
+                    word.word = word.to_titlecase_tr_or_az_lower_rest();
+                } else {
+                    word.word = match is_reserved(word) {
+                        true => word.word.to_lowercase_tr_az(),
+                        false => word.word.to_titlecase_tr_or_az_lower_rest(),
+                    };
                 }
             }
-        }
-    });
+        });
     chunk.into()
 }
 
 fn is_reserved(word: &Word) -> bool {
-    let word = word.to_string();
-    let word = word.as_ref();
-    let baglac =
-        Regex::new(r"^([Vv][Ee]|[İi][Ll][Ee]|[Yy][Aa]|[Yy][Aa][Hh][Uu][Tt]|[Kk][İi]|[Dd][AaEe])$")
-            .unwrap();
-    let soruek = Regex::new(r"^([Mm][İiIıUuÜü])([Dd][İiIıUuÜü][Rr]([Ll][AaEe][Rr])?|[Ss][İiIıUuÜü][Nn]|[Yy][İiIıUuÜü][Zz]|[Ss][İiIıUuÜü][Nn][İiIıUuÜü][Zz]|[Ll][AaEe][Rr])?$").unwrap();
-    baglac.is_match(word) || soruek.is_match(word)
+    let word = word.word.as_str();
+    BAGLAC.is_match(word) || SORUEK.is_match(word)
 }
 
-pub fn lowercase(chunk: Chunk) -> String {
-    let mut chunk = chunk.clone();
+pub fn lowercase(mut chunk: Chunk) -> String {
     chunk.segments.iter_mut().for_each(|segment| {
         if let Segment::Word(word) = segment {
             word.word = word.word.to_lowercase_tr_az()
@@ -60,8 +80,7 @@ pub fn lowercase(chunk: Chunk) -> String {
     chunk.into()
 }
 
-pub fn uppercase(chunk: Chunk) -> String {
-    let mut chunk = chunk.clone();
+pub fn uppercase(mut chunk: Chunk) -> String {
     chunk.segments.iter_mut().for_each(|segment| {
         if let Segment::Word(word) = segment {
             word.word = word.word.to_uppercase_tr_az()
@@ -70,8 +89,7 @@ pub fn uppercase(chunk: Chunk) -> String {
     chunk.into()
 }
 
-pub fn sentencecase(chunk: Chunk) -> String {
-    let mut chunk = chunk.clone();
+pub fn sentencecase(mut chunk: Chunk) -> String {
     let mut done_first = false;
     chunk.segments.iter_mut().for_each(|segment| {
         if let Segment::Word(word) = segment {
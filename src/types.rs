@@ -5,7 +5,7 @@ use snafu::prelude::*;
 use std::convert::{Infallible, TryFrom};
 use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
-use strum_macros::{Display, VariantNames};
+use strum_macros::{Display, EnumIter, VariantNames};
 
 #[cfg(feature = "pythonmodule")]
 use pyo3::prelude::*;
@@ -27,6 +27,24 @@ pub enum Error {
 
     #[snafu(display("Invalid style options '{input}'"))]
     StyleOptions { input: String },
+
+    #[snafu(display("Invalid style guide fallback policy '{input}'"))]
+    StyleFallback { input: String },
+
+    #[snafu(display("Invalid acronym policy '{input}'"))]
+    Acronyms { input: String },
+
+    #[snafu(display("Invalid sharp s policy '{input}'"))]
+    Eszett { input: String },
+
+    #[snafu(display("Invalid Mtavruli policy '{input}'"))]
+    Mtavruli { input: String },
+
+    #[snafu(display("Invalid subtitle policy '{input}'"))]
+    Subtitles { input: String },
+
+    #[snafu(display("Style guide '{style}' is not supported for language '{locale}'"))]
+    UnsupportedStyleGuide { locale: Locale, style: StyleGuide },
 }
 
 // Clap CLI errors are reported using the Debug trait, but Snafu sets up the Display trait.
@@ -58,7 +76,7 @@ pub struct Word {
 // or logically or whatever) when a major version with no ABI compatibility guarantees is okay.
 
 /// Locale selector to change language support rules of case functions.
-#[derive(Default, Display, VariantNames, Debug, Clone, Copy, PartialEq)]
+#[derive(Default, Display, EnumIter, VariantNames, Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "pythonmodule", pyclass(eq))]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
 #[strum(serialize_all = "lowercase")]
@@ -68,6 +86,28 @@ pub enum Locale {
     EN,
     TR,
     ES,
+    DE,
+    FR,
+    IT,
+    PT,
+    NL,
+    AZ,
+    EL,
+    CA,
+    PL,
+    CS,
+    SK,
+    SR,
+    HR,
+    BS,
+    RU,
+    UK,
+    GA,
+    CY,
+    LT,
+    KA,
+    #[strum(serialize = "pt-pt")]
+    PTPT,
 }
 
 /// Target case selector.
@@ -107,23 +147,221 @@ pub enum StyleGuide {
     RealAcademiaEspanola,
     #[strum(serialize = "fundeu")]
     FundeuRealAcademiaEspanola,
+    #[strum(serialize = "din")]
+    DeutschesInstitutFurNormung,
+    #[strum(serialize = "imprimerie")]
+    ImprimerieNationale,
+    /// Capitalize everything but reserved words in languages that normally prefer sentence style
+    #[strum(serialize = "majorwords")]
+    MajorWords,
+    #[strum(serialize = "abnt")]
+    AssociacaoBrasileiraDeNormasTecnicas,
+    #[strum(serialize = "acl")]
+    AcademiaDasCienciasDeLisboa,
+    #[strum(serialize = "iec")]
+    InstitutDEstudisCatalans,
+    /// Sentence style that also capitalizes the word after an elided article or preposition at
+    /// the start
+    #[strum(serialize = "elision")]
+    CapitalizeAfterElision,
+}
+
+/// Policy selector for what to do when a style guide is not available for a locale.
+#[derive(Default, Display, VariantNames, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "pythonmodule", pyclass(eq))]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum StyleFallback {
+    #[default]
+    Error,
+    #[strum(serialize = "default")]
+    LanguageDefault,
+    Closest,
+}
+
+/// Policy selector for words that look like acronyms or otherwise have intentional mixed case.
+#[derive(Default, Display, VariantNames, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "pythonmodule", pyclass(eq))]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum Acronyms {
+    #[default]
+    Recase,
+    Keep,
+}
+
+/// Policy selector for how to uppercase the German sharp s (ß).
+#[derive(Default, Display, VariantNames, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "pythonmodule", pyclass(eq))]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum Eszett {
+    /// Replace with a double S as in traditional orthography (STRASSE)
+    #[default]
+    #[strum(serialize = "ss")]
+    Double,
+    /// Use the capital sharp s allowed since the 2017 spelling reform (STRAẞE)
+    Capital,
+}
+
+/// Policy selector for how to uppercase Georgian, which has no case distinction in normal writing.
+#[derive(Default, Display, VariantNames, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "pythonmodule", pyclass(eq))]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum Mtavruli {
+    /// Convert to the Mtavruli capitals added in Unicode 11 (ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ)
+    #[default]
+    Capital,
+    /// Leave Mkhedruli letters as they are (საქართველო)
+    Keep,
 }
 
+/// Policy selector for the first word of a subtitle following a colon in sentence style titles.
+#[derive(Default, Display, VariantNames, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "pythonmodule", pyclass(eq))]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum Subtitles {
+    /// Style the subtitle as part of the same sentence (Война и мир: роман)
+    #[default]
+    Lower,
+    /// Capitalize the first word of the subtitle as some house styles do (Война и мир: Роман)
+    Capitalize,
+}
+
+/// Options to fine tune how a style guide is applied.
+///
+/// Besides the builder, options can be parsed from a string of `key=value` pairs separated by
+/// semicolons. The recognized keys are `overrides` (a comma separated list of words or phrases),
+/// `fallback`, `acronyms`, `eszett`, `mtavruli`, and `subtitles`, e.g.
+/// `overrides=iOS,GitHub;acronyms=keep;fallback=default`. A comma, semicolon, or backslash that is
+/// part of an override needs to be escaped with a backslash, as in `overrides=Hello\, World`. An
+/// empty string, `default`, or `none` give the default options. Formatting options for display
+/// gives back the same syntax.
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "pythonmodule", pyclass(eq))]
 #[cfg_attr(feature = "wasm", wasm_bindgen(getter_with_clone))]
 pub struct StyleOptions {
     pub overrides: Option<Vec<Word>>,
+    pub fallback: StyleFallback,
+    pub acronyms: Acronyms,
+    pub eszett: Eszett,
+    pub mtavruli: Mtavruli,
+    pub subtitles: Subtitles,
 }
 
 impl FromStr for StyleOptions {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
-        match s.to_ascii_lowercase().as_str() {
-            "default" | "none" | "" => Ok(StyleOptions::default()),
-            input => StyleOptionsSnafu { input }.fail()?,
+        let mut builder = StyleOptionsBuilder::new();
+        for setting in split_unescaped(s, ';')
+            .into_iter()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            let (key, value) = match setting.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (setting, ""),
+            };
+            builder = match key.to_ascii_lowercase().as_str() {
+                "default" | "none" if value.is_empty() => builder,
+                "overrides" | "override" => builder.overrides(
+                    split_unescaped(value, ',')
+                        .into_iter()
+                        .map(str::trim)
+                        .filter(|word| !word.is_empty())
+                        .map(|word| Word {
+                            word: unescape(word),
+                        })
+                        .collect(),
+                ),
+                "fallback" => builder.fallback(value.parse()?),
+                "acronyms" => builder.acronyms(value.parse()?),
+                "eszett" => builder.eszett(value.parse()?),
+                "mtavruli" => builder.mtavruli(value.parse()?),
+                "subtitles" => builder.subtitles(value.parse()?),
+                _ => StyleOptionsSnafu { input: s }.fail()?,
+            };
+        }
+        Ok(builder.build())
+    }
+}
+
+impl Display for StyleOptions {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        let mut settings: Vec<String> = Vec::new();
+        if let Some(overrides) = &self.overrides {
+            let words: Vec<String> = overrides.iter().map(|w| escape(&w.word)).collect();
+            settings.push(format!("overrides={}", words.join(",")));
+        }
+        if self.fallback != StyleFallback::default() {
+            settings.push(format!("fallback={}", self.fallback));
+        }
+        if self.acronyms != Acronyms::default() {
+            settings.push(format!("acronyms={}", self.acronyms));
+        }
+        if self.eszett != Eszett::default() {
+            settings.push(format!("eszett={}", self.eszett));
+        }
+        if self.mtavruli != Mtavruli::default() {
+            settings.push(format!("mtavruli={}", self.mtavruli));
+        }
+        if self.subtitles != Subtitles::default() {
+            settings.push(format!("subtitles={}", self.subtitles));
+        }
+        match settings.is_empty() {
+            true => fmt.write_str("default"),
+            false => fmt.write_str(&settings.join(";")),
+        }
+    }
+}
+
+// Split on a delimiter unless it is escaped with a backslash, leaving any escapes in place
+fn split_unescaped(s: &str, delimiter: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (idx, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == delimiter {
+            parts.push(&s[start..idx]);
+            start = idx + c.len_utf8();
         }
     }
+    parts.push(&s[start..]);
+    parts
+}
+
+fn escape(word: &str) -> String {
+    let mut output = String::with_capacity(word.len());
+    for c in word.chars() {
+        if matches!(c, '\\' | ',' | ';') {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+    output
+}
+
+fn unescape(word: &str) -> String {
+    let mut output = String::with_capacity(word.len());
+    let mut chars = word.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => output.extend(chars.next()),
+            c => output.push(c),
+        }
+    }
+    output
 }
 
 impl TryFrom<&str> for StyleOptions {
@@ -158,6 +396,11 @@ impl TryFrom<&[u8]> for StyleOptions {
 #[derive(Debug)]
 pub struct StyleOptionsBuilder {
     overrides: Option<Vec<Word>>,
+    fallback: StyleFallback,
+    acronyms: Acronyms,
+    eszett: Eszett,
+    mtavruli: Mtavruli,
+    subtitles: Subtitles,
 }
 
 impl Default for StyleOptionsBuilder {
@@ -168,7 +411,14 @@ impl Default for StyleOptionsBuilder {
 
 impl StyleOptionsBuilder {
     pub fn new() -> Self {
-        Self { overrides: None }
+        Self {
+            overrides: None,
+            fallback: StyleFallback::default(),
+            acronyms: Acronyms::default(),
+            eszett: Eszett::default(),
+            mtavruli: Mtavruli::default(),
+            subtitles: Subtitles::default(),
+        }
     }
 
     pub fn overrides(mut self, words: Vec<impl Into<Word>>) -> Self {
@@ -177,9 +427,39 @@ impl StyleOptionsBuilder {
         self
     }
 
+    pub fn fallback(mut self, fallback: StyleFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    pub fn acronyms(mut self, acronyms: Acronyms) -> Self {
+        self.acronyms = acronyms;
+        self
+    }
+
+    pub fn eszett(mut self, eszett: Eszett) -> Self {
+        self.eszett = eszett;
+        self
+    }
+
+    pub fn mtavruli(mut self, mtavruli: Mtavruli) -> Self {
+        self.mtavruli = mtavruli;
+        self
+    }
+
+    pub fn subtitles(mut self, subtitles: Subtitles) -> Self {
+        self.subtitles = subtitles;
+        self
+    }
+
     pub fn build(self) -> StyleOptions {
         StyleOptions {
             overrides: self.overrides,
+            fallback: self.fallback,
+            acronyms: self.acronyms,
+            eszett: self.eszett,
+            mtavruli: self.mtavruli,
+            subtitles: self.subtitles,
         }
     }
 }
@@ -191,6 +471,29 @@ impl FromStr for Locale {
             "en" | "english" | "en_en" => Ok(Locale::EN),
             "es" | "spanish" | "es_es" | "espanol" | "español" => Ok(Locale::ES),
             "tr" | "turkish" | "tr_tr" | "turkce" | "türkçe" => Ok(Locale::TR),
+            "de" | "german" | "de_de" | "deutsch" => Ok(Locale::DE),
+            "fr" | "french" | "fr_fr" | "francais" | "français" => Ok(Locale::FR),
+            "it" | "italian" | "it_it" | "italiano" => Ok(Locale::IT),
+            "pt" | "portuguese" | "pt_br" | "pt-br" | "portugues" | "português" => Ok(Locale::PT),
+            "nl" | "dutch" | "nl_nl" | "nederlands" => Ok(Locale::NL),
+            "az" | "azerbaijani" | "az_az" | "az-latn" | "az_latn" | "azərbaycan"
+            | "azərbaycanca" => Ok(Locale::AZ),
+            "el" | "greek" | "el_gr" | "ellinika" | "ελληνικά" => Ok(Locale::EL),
+            "ca" | "catalan" | "ca_es" | "catala" | "català" => Ok(Locale::CA),
+            "pl" | "polish" | "pl_pl" | "polski" => Ok(Locale::PL),
+            "cs" | "czech" | "cs_cz" | "cestina" | "čeština" => Ok(Locale::CS),
+            "sk" | "slovak" | "sk_sk" | "slovencina" | "slovenčina" => Ok(Locale::SK),
+            "sr" | "serbian" | "sr_rs" | "sr-latn" | "sr_latn" | "sr-cyrl" | "sr_cyrl"
+            | "srpski" | "српски" => Ok(Locale::SR),
+            "hr" | "croatian" | "hr_hr" | "hrvatski" => Ok(Locale::HR),
+            "bs" | "bosnian" | "bs_ba" | "bosanski" => Ok(Locale::BS),
+            "ru" | "russian" | "ru_ru" | "russkiy" | "русский" => Ok(Locale::RU),
+            "uk" | "ukrainian" | "uk_ua" | "ukrainska" | "українська" => Ok(Locale::UK),
+            "ga" | "irish" | "ga_ie" | "gaeilge" => Ok(Locale::GA),
+            "cy" | "welsh" | "cy_gb" | "cymraeg" => Ok(Locale::CY),
+            "lt" | "lithuanian" | "lt_lt" | "lietuviu" | "lietuvių" => Ok(Locale::LT),
+            "ka" | "georgian" | "ka_ge" | "kartuli" | "ქართული" => Ok(Locale::KA),
+            "pt-pt" | "pt_pt" => Ok(Locale::PTPT),
             input => LocaleSnafu { input }.fail()?,
         }
     }
@@ -269,6 +572,22 @@ impl TryFrom<&[u8]> for Case {
     }
 }
 
+impl StyleGuide {
+    /// Whether the guide styles titles like sentences rather than capitalizing every major word
+    ///
+    /// What the language default does depends on the locale, so it doesn't count as either.
+    pub fn is_sentence_style(&self) -> bool {
+        matches!(
+            self,
+            StyleGuide::DeutschesInstitutFurNormung
+                | StyleGuide::ImprimerieNationale
+                | StyleGuide::AssociacaoBrasileiraDeNormasTecnicas
+                | StyleGuide::InstitutDEstudisCatalans
+                | StyleGuide::CapitalizeAfterElision
+        )
+    }
+}
+
 impl FromStr for StyleGuide {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
@@ -279,6 +598,17 @@ impl FromStr for StyleGuide {
             "fundeu" | "fundeurealacademiaespanola" => Ok(StyleGuide::FundeuRealAcademiaEspanola),
             "rae" | "realacademiaespanola" => Ok(StyleGuide::RealAcademiaEspanola),
             "tdk" | "turkishlanguageinstitute" => Ok(StyleGuide::TurkishLanguageInstitute),
+            "din" | "deutschesinstitutfurnormung" => Ok(StyleGuide::DeutschesInstitutFurNormung),
+            "imprimerie" | "imprimerienationale" | "in" | "lexique" => {
+                Ok(StyleGuide::ImprimerieNationale)
+            }
+            "majorwords" | "major" => Ok(StyleGuide::MajorWords),
+            "abnt" | "associacaobrasileiradenormastecnicas" => {
+                Ok(StyleGuide::AssociacaoBrasileiraDeNormasTecnicas)
+            }
+            "acl" | "academiadascienciasdelisboa" => Ok(StyleGuide::AcademiaDasCienciasDeLisboa),
+            "iec" | "institutdestudiscatalans" => Ok(StyleGuide::InstitutDEstudisCatalans),
+            "elision" | "capitalizeafterelision" => Ok(StyleGuide::CapitalizeAfterElision),
             "default" | "languagedefault" | "language" | "none" | "" => {
                 Ok(StyleGuide::LanguageDefault)
             }
@@ -325,3 +655,209 @@ impl TryFrom<&[u8]> for StyleGuide {
         Self::from_str(&s)
     }
 }
+
+impl FromStr for StyleFallback {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" | "none" | "" => Ok(StyleFallback::Error),
+            "default" | "languagedefault" | "language" => Ok(StyleFallback::LanguageDefault),
+            "closest" | "compatible" => Ok(StyleFallback::Closest),
+            input => StyleFallbackSnafu { input }.fail()?,
+        }
+    }
+}
+
+impl TryFrom<&str> for StyleFallback {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<String> for StyleFallback {
+    type Error = Error;
+    fn try_from(s: String) -> Result<Self> {
+        Self::from_str(&s)
+    }
+}
+
+impl TryFrom<&String> for StyleFallback {
+    type Error = Error;
+    fn try_from(s: &String) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&[u8]> for StyleFallback {
+    type Error = Error;
+
+    fn try_from(s: &[u8]) -> Result<Self> {
+        let s = String::from_utf8_lossy(s);
+        Self::from_str(&s)
+    }
+}
+
+impl FromStr for Acronyms {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "recase" | "default" | "none" | "" => Ok(Acronyms::Recase),
+            "keep" | "preserve" => Ok(Acronyms::Keep),
+            input => AcronymsSnafu { input }.fail()?,
+        }
+    }
+}
+
+impl TryFrom<&str> for Acronyms {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<String> for Acronyms {
+    type Error = Error;
+    fn try_from(s: String) -> Result<Self> {
+        Self::from_str(&s)
+    }
+}
+
+impl TryFrom<&String> for Acronyms {
+    type Error = Error;
+    fn try_from(s: &String) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&[u8]> for Acronyms {
+    type Error = Error;
+
+    fn try_from(s: &[u8]) -> Result<Self> {
+        let s = String::from_utf8_lossy(s);
+        Self::from_str(&s)
+    }
+}
+
+impl FromStr for Eszett {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ss" | "double" | "default" | "none" | "" => Ok(Eszett::Double),
+            "capital" | "ß" => Ok(Eszett::Capital),
+            input => EszettSnafu { input }.fail()?,
+        }
+    }
+}
+
+impl TryFrom<&str> for Eszett {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<String> for Eszett {
+    type Error = Error;
+    fn try_from(s: String) -> Result<Self> {
+        Self::from_str(&s)
+    }
+}
+
+impl TryFrom<&String> for Eszett {
+    type Error = Error;
+    fn try_from(s: &String) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&[u8]> for Eszett {
+    type Error = Error;
+
+    fn try_from(s: &[u8]) -> Result<Self> {
+        let s = String::from_utf8_lossy(s);
+        Self::from_str(&s)
+    }
+}
+
+impl FromStr for Mtavruli {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "capital" | "mtavruli" | "default" | "none" | "" => Ok(Mtavruli::Capital),
+            "keep" | "mkhedruli" => Ok(Mtavruli::Keep),
+            input => MtavruliSnafu { input }.fail()?,
+        }
+    }
+}
+
+impl TryFrom<&str> for Mtavruli {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<String> for Mtavruli {
+    type Error = Error;
+    fn try_from(s: String) -> Result<Self> {
+        Self::from_str(&s)
+    }
+}
+
+impl TryFrom<&String> for Mtavruli {
+    type Error = Error;
+    fn try_from(s: &String) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&[u8]> for Mtavruli {
+    type Error = Error;
+
+    fn try_from(s: &[u8]) -> Result<Self> {
+        let s = String::from_utf8_lossy(s);
+        Self::from_str(&s)
+    }
+}
+
+impl FromStr for Subtitles {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "lower" | "lowercase" | "default" | "none" | "" => Ok(Subtitles::Lower),
+            "capitalize" | "capital" | "titlecase" => Ok(Subtitles::Capitalize),
+            input => SubtitlesSnafu { input }.fail()?,
+        }
+    }
+}
+
+impl TryFrom<&str> for Subtitles {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<String> for Subtitles {
+    type Error = Error;
+    fn try_from(s: String) -> Result<Self> {
+        Self::from_str(&s)
+    }
+}
+
+impl TryFrom<&String> for Subtitles {
+    type Error = Error;
+    fn try_from(s: &String) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&[u8]> for Subtitles {
+    type Error = Error;
+
+    fn try_from(s: &[u8]) -> Result<Self> {
+        let s = String::from_utf8_lossy(s);
+        Self::from_str(&s)
+    }
+}
@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: © 2026 Caleb Maclennan <caleb@alerque.com>
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use crate::content::Chunk;
+use crate::generics::{ReservedWords, titlecase_sentence_or_major_words};
+use crate::types::{Locale, Result, StyleGuide, StyleOptions};
+
+use std::sync::LazyLock;
+
+pub use crate::generics::{
+    SENTENCE_STYLE_GUIDES as STYLE_GUIDES, closest_sentence_style as closest_style, lowercase,
+    titlecase_sentence_style as sentencecase, uppercase,
+};
+
+// Prepositions, conjunctions, and particles
+static RESERVED: LazyLock<ReservedWords> = LazyLock::new(|| {
+    ReservedWords::from_slice(&[
+        "а",
+        "або",
+        "але",
+        "без",
+        "в",
+        "від",
+        "для",
+        "до",
+        "з",
+        "за",
+        "зі",
+        "й",
+        "і",
+        "із",
+        "на",
+        "над",
+        "не",
+        "ні",
+        "по",
+        "під",
+        "при",
+        "про",
+        "та",
+        "у",
+        "через",
+        "чи",
+    ])
+});
+
+// Ukrainian spelling rules and the DSTU bibliographic standards only capitalize the first word of a
+// title and proper nouns in it, and a subtitle after a colon is lowercased unless asked otherwise.
+pub fn titlecase(chunk: Chunk, style: StyleGuide, opts: &StyleOptions) -> Result<String> {
+    titlecase_sentence_or_major_words(chunk, Locale::UK, style, opts, &RESERVED)
+}
@@ -43,3 +43,13 @@ pub fn uppercase(input: &str, locale: Locale) -> Result<String, JsError> {
 pub fn sentencecase(input: &str, locale: Locale) -> Result<String, JsError> {
     Ok(crate::sentencecase(input, locale)?)
 }
+
+#[wasm_bindgen]
+pub fn supported_styles(locale: Locale) -> Vec<StyleGuide> {
+    crate::supported_styles(locale)
+}
+
+#[wasm_bindgen]
+pub fn supported_locales(style: StyleGuide) -> Vec<Locale> {
+    crate::supported_locales(style)
+}
@@ -48,6 +48,27 @@ fn main_overrides() {
         .stderr("");
 }
 
+#[cfg(feature = "cli")]
+#[test]
+fn main_options() {
+    let mut cmd = cargo_bin_cmd!(env!("CARGO_PKG_NAME"));
+    cmd.args(["--options", "overrides=iOS;acronyms=keep", "ios and NASA"])
+        .assert()
+        .success()
+        .stdout("iOS and NASA\n")
+        .stderr("");
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn main_options_invalid() {
+    let mut cmd = cargo_bin_cmd!(env!("CARGO_PKG_NAME"));
+    cmd.args(["--options", "foo=bar", "baz"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid style options"));
+}
+
 #[cfg(feature = "cli")]
 #[test]
 fn main_lang() {
@@ -66,3 +87,25 @@ fn main_lang() {
         .stdout("İlk\n")
         .stderr("");
 }
+
+#[cfg(feature = "cli")]
+#[test]
+fn main_list_styles() {
+    let mut cmd = cargo_bin_cmd!(env!("CARGO_PKG_NAME"));
+    cmd.args(["-l", "es", "--list-styles"])
+        .assert()
+        .success()
+        .stdout("default\nrae\nfundeu\n")
+        .stderr("");
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn main_list_locales() {
+    let mut cmd = cargo_bin_cmd!(env!("CARGO_PKG_NAME"));
+    cmd.args(["-s", "cmos", "--list-locales"])
+        .assert()
+        .success()
+        .stdout("en\n")
+        .stderr("");
+}
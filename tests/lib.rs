@@ -34,6 +34,152 @@ fn custom_style_guide() {
     assert_eq!(res, "fOO Bar");
 }
 
+#[test]
+fn unsupported_style_guide() {
+    let res = titlecase("foo", "tr", "cmos", "default");
+    assert!(matches!(
+        res,
+        Err(Error::UnsupportedStyleGuide {
+            locale: Locale::TR,
+            style: StyleGuide::ChicagoManualOfStyle,
+        })
+    ));
+    let res = case("foo", "title", "en", "rae", "default");
+    assert!(matches!(res, Err(Error::UnsupportedStyleGuide { .. })));
+}
+
+#[test]
+fn style_fallback() {
+    let text = "foo: a baz";
+    let options = StyleOptionsBuilder::new()
+        .fallback(StyleFallback::LanguageDefault)
+        .build();
+    let res = titlecase(text, "en", "rae", options).unwrap();
+    assert_eq!(res, "Foo: A Baz");
+    let options = StyleOptionsBuilder::new()
+        .fallback(StyleFallback::Closest)
+        .build();
+    let res = titlecase(text, "en", "rae", options.clone()).unwrap();
+    assert_eq!(res, "Foo: a Baz");
+    let res = titlecase("sen ve ben", "tr", "cmos", options.clone()).unwrap();
+    assert_eq!(res, "Sen ve Ben");
+    let res = titlecase("il nome della rosa", "it", "iec", options.clone()).unwrap();
+    assert_eq!(res, "Il nome della rosa");
+    let res = titlecase("il nome della rosa", "it", "cmos", options.clone()).unwrap();
+    assert_eq!(res, "Il Nome della Rosa");
+    let res = titlecase("o nome da rosa", "pt", "din", options).unwrap();
+    assert_eq!(res, "O nome da rosa");
+}
+
+#[test]
+fn capabilities() {
+    let styles = supported_styles(Locale::TR);
+    assert_eq!(
+        styles,
+        vec![
+            StyleGuide::LanguageDefault,
+            StyleGuide::TurkishLanguageInstitute
+        ]
+    );
+    let locales = supported_locales(StyleGuide::RealAcademiaEspanola);
+    assert_eq!(locales, vec![Locale::ES]);
+    let locales = supported_locales(StyleGuide::LanguageDefault);
+    assert_eq!(
+        locales,
+        vec![
+            Locale::EN,
+            Locale::TR,
+            Locale::ES,
+            Locale::DE,
+            Locale::FR,
+            Locale::IT,
+            Locale::PT,
+            Locale::NL,
+            Locale::AZ,
+            Locale::EL,
+            Locale::CA,
+            Locale::PL,
+            Locale::CS,
+            Locale::SK,
+            Locale::SR,
+            Locale::HR,
+            Locale::BS,
+            Locale::RU,
+            Locale::UK,
+            Locale::GA,
+            Locale::CY,
+            Locale::LT,
+            Locale::KA,
+            Locale::PTPT
+        ]
+    );
+}
+
+#[test]
+fn prepared_decasifier() {
+    let decasifier = Decasifier::new("title", "tr", "tdk", "default").unwrap();
+    assert_eq!(decasifier.apply("ILIK SU").unwrap(), "Ilık Su");
+    assert_eq!(decasifier.apply("sen ve ben").unwrap(), "Sen ve Ben");
+    let decasifier = Decasifier::new(Case::Lower, Locale::EN, "rae", "default").unwrap();
+    assert_eq!(decasifier.apply("FOO BAR").unwrap(), "foo bar");
+    let decasifier =
+        Decasifier::new("title", "en", "cmos", "overrides=new,New York Times").unwrap();
+    assert_eq!(
+        decasifier.apply("the new york times").unwrap(),
+        "The New York Times"
+    );
+    assert_eq!(decasifier.apply("new and old").unwrap(), "new and Old");
+    assert!(Decasifier::new("title", "en", "rae", "default").is_err());
+    assert!(Decasifier::new("title", "xx", "default", "default").is_err());
+    assert!(Decasifier::new("title", "pt", "pt-br", "default").is_err());
+}
+
+#[test]
+fn style_options_syntax() {
+    let text = "overrides=iOS,GitHub;fallback=default;acronyms=keep";
+    let options = StyleOptions::try_from(text).unwrap();
+    let expected = StyleOptionsBuilder::new()
+        .overrides(vec!["iOS", "GitHub"])
+        .fallback(StyleFallback::LanguageDefault)
+        .acronyms(Acronyms::Keep)
+        .build();
+    assert_eq!(options, expected);
+    assert_eq!(options.to_string(), text);
+    let options = StyleOptions::try_from(" acronyms = keep ; ").unwrap();
+    assert_eq!(options.to_string(), "acronyms=keep");
+    let options = StyleOptions::try_from("mtavruli=keep").unwrap();
+    assert_eq!(options.mtavruli, Mtavruli::Keep);
+    assert_eq!(options.to_string(), "mtavruli=keep");
+    assert_eq!(StyleOptions::default().to_string(), "default");
+    let options = StyleOptionsBuilder::new()
+        .overrides(vec!["Hello, World", "a;b", r"C:\"])
+        .build();
+    let text = r"overrides=Hello\, World,a\;b,C:\\";
+    assert_eq!(options.to_string(), text);
+    assert_eq!(StyleOptions::try_from(text).unwrap(), options);
+    assert_eq!(
+        StyleOptions::try_from("none").unwrap(),
+        StyleOptions::default()
+    );
+    assert!(StyleOptions::try_from("foo=bar").is_err());
+    assert!(StyleOptions::try_from("acronyms=maybe").is_err());
+    assert!(StyleOptions::try_from("mtavruli=maybe").is_err());
+    let options = StyleOptions::try_from("mtavruli=none").unwrap();
+    assert_eq!(options.mtavruli, Mtavruli::Capital);
+    let options = StyleOptions::try_from("subtitles=capitalize").unwrap();
+    assert_eq!(options.subtitles, Subtitles::Capitalize);
+    assert_eq!(options.to_string(), "subtitles=capitalize");
+    assert!(StyleOptions::try_from("subtitles=maybe").is_err());
+    let res = titlecase(
+        "ios on NASA for a foo",
+        "en",
+        "rae",
+        "acronyms=keep;fallback=closest;overrides=iOS",
+    )
+    .unwrap();
+    assert_eq!(res, "iOS on NASA for a Foo");
+}
+
 #[cfg(feature = "unstable-trait")]
 #[test]
 fn trait_chery() {
@@ -183,6 +329,219 @@ titlecase!(
     "Foo: A Baz"
 );
 
+titlecase!(
+    abc_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptions::default(),
+    "a b c",
+    "A B C"
+);
+
+titlecase!(
+    simple_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptions::default(),
+    "the LORD OF the rings",
+    "The Lord of the Rings"
+);
+
+titlecase!(
+    long_prepositions_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptions::default(),
+    "a walk through the woods with a friend",
+    "A Walk Through the Woods With a Friend"
+);
+
+titlecase!(
+    last_word_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptions::default(),
+    "a place to come back to",
+    "A Place to Come Back To"
+);
+
+titlecase!(
+    overrides_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptionsBuilder::new().overrides(vec!["iOS"]).build(),
+    "news for ios users",
+    "News for iOS Users"
+);
+
+titlecase!(
+    hyphens_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "my SELF-DRIVING e-mail from a mother-in-law",
+    "My Self-Driving E-mail from a Mother-in-Law"
+);
+
+titlecase!(
+    hyphens_numbers_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "twenty-one anti-inflammatory pills for the 1939–1945 post-war years",
+    "Twenty-One Anti-inflammatory Pills for the 1939–1945 Post-war Years"
+);
+
+titlecase!(
+    hyphens_proper_after_prefix_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "non-English speakers and anti-American views",
+    "Non-English Speakers and Anti-American Views"
+);
+
+titlecase!(
+    hyphens_single_letter_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "an x-ray of the t-shirt",
+    "An X-Ray of the T-Shirt"
+);
+
+titlecase!(
+    hyphens_last_word_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "the big run-in",
+    "The Big Run-In"
+);
+
+titlecase!(
+    hyphens_title_case_input_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "Pre-War Anti-Inflation Measures",
+    "Pre-war Anti-inflation Measures"
+);
+
+titlecase!(
+    hyphens_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptions::default(),
+    "an up-to-date look at anti-inflammatory drugs",
+    "An Up-to-Date Look at Anti-Inflammatory Drugs"
+);
+
+titlecase!(
+    phrase_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "stay as long as you like",
+    "Stay as long as You Like"
+);
+
+titlecase!(
+    phrase_punctuation_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "stay as long, as you like",
+    "Stay as Long, as You Like"
+);
+
+titlecase!(
+    phrase_overrides_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["the Who"])
+        .build(),
+    "the who and the what",
+    "the Who and the What"
+);
+
+titlecase!(
+    phrase_overrides_gruber,
+    Locale::EN,
+    StyleGuide::DaringFireball,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["New York Times", "of the Rings"])
+        .build(),
+    "the lord OF THE RINGS review in the new york times",
+    "The Lord of the Rings Review in the New York Times"
+);
+
+titlecase!(
+    phrase_overrides_tr,
+    Locale::TR,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["ve Ortağı"])
+        .build(),
+    "ahmet VE ORTAĞI ile",
+    "Ahmet ve Ortağı ile"
+);
+
+titlecase!(
+    punctuation_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "“the lord (and his rings)”",
+    "“The Lord (and His Rings)”"
+);
+
+titlecase!(
+    punctuation_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptions::default(),
+    "'tis the season... of giving",
+    "'Tis the Season... of Giving"
+);
+
+titlecase!(
+    acronyms_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptionsBuilder::new().acronyms(Acronyms::Keep).build(),
+    "NASA and the iPhone of McDonald",
+    "NASA and the iPhone of McDonald"
+);
+
+titlecase!(
+    acronyms_recase_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptions::default(),
+    "NASA and the iPhone of McDonald",
+    "Nasa and the Iphone of Mcdonald"
+);
+
+titlecase!(
+    acronyms_shouting_cmos,
+    Locale::EN,
+    StyleGuide::ChicagoManualOfStyle,
+    StyleOptionsBuilder::new().acronyms(Acronyms::Keep).build(),
+    "NASA LAUNCHES A ROCKET",
+    "Nasa Launches a Rocket"
+);
+
+titlecase!(
+    acronyms_ap,
+    Locale::EN,
+    StyleGuide::AssociatedPress,
+    StyleOptionsBuilder::new().acronyms(Acronyms::Keep).build(),
+    "Q&A with steve jobs on the iPod years",
+    "Q&A With Steve Jobs on the iPod Years"
+);
+
 // titlecase!(
 //     qna_cmos,
 //     Locale::EN,
@@ -254,6 +613,24 @@ titlecase!(
     "En la Casa de mi Madre"
 );
 
+titlecase!(
+    rae_punctuation,
+    Locale::ES,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "¿qué pasa con «el libro»?",
+    "¿Qué Pasa con «el Libro»?"
+);
+
+titlecase!(
+    rae_acronyms,
+    Locale::ES,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().acronyms(Acronyms::Keep).build(),
+    "la UNESCO y el iPhone",
+    "La UNESCO y el iPhone"
+);
+
 titlecase!(
     turkish_question,
     Locale::TR,
@@ -290,6 +667,24 @@ titlecase!(
     "Sen ve Ben ile O"
 );
 
+titlecase!(
+    turkish_punctuation,
+    Locale::TR,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "«sen VE ben» (ılık)",
+    "«Sen ve Ben» (Ilık)"
+);
+
+titlecase!(
+    turkish_acronyms,
+    Locale::TR,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().acronyms(Acronyms::Keep).build(),
+    "TBMM ve milli İRADE",
+    "TBMM ve Milli İRADE"
+);
+
 titlecase!(
     turkish_ws,
     Locale::TR,
@@ -299,65 +694,980 @@ titlecase!(
     "  Serbest  Serseri\n  Boşluk  "
 );
 
-macro_rules! lowercase {
-    ($name:ident, $locale:expr, $input:expr, $expected:expr) => {
-        #[test]
-        fn $name() {
-            let actual = lowercase($input, $locale).unwrap();
-            assert_eq!(actual, $expected);
-        }
-    };
-}
-
-lowercase!(lower_en, Locale::EN, "foo BAR BaZ BIKE", "foo bar baz bike");
+titlecase!(
+    german_nouns,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "DIE ZEITUNG ÜBER DIE FREIHEIT DER PRESSE",
+    "Die Zeitung über die Freiheit der Presse"
+);
 
-lowercase!(lower_es, Locale::ES, "Hola MUNDO", "hola mundo");
+titlecase!(
+    german_din,
+    Locale::DE,
+    StyleGuide::DeutschesInstitutFurNormung,
+    StyleOptions::default(),
+    "Die Geschichte Der Stadt Und Ihr Volk",
+    "Die Geschichte der Stadt und ihr Volk"
+);
 
-lowercase!(
-    lower_tr,
-    Locale::TR,
-    "foo BAR BaZ ILIK İLE",
-    "foo bar baz ılık ile"
+case!(
+    german_sentence_title_case_input,
+    Case::Sentence,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "Die Neue Zeit Im Alten Haus Springt",
+    "Die neue Zeit im alten Haus springt"
 );
 
-macro_rules! uppercase {
-    ($name:ident, $locale:expr, $input:expr, $expected:expr) => {
-        #[test]
-        fn $name() {
-            let actual = uppercase($input, $locale).unwrap();
-            assert_eq!(actual, $expected);
-        }
-    };
-}
+case!(
+    german_lower_title_case_input,
+    Case::Lower,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "Die Neue Zeit Im Alten Haus Springt",
+    "die neue Zeit im alten Haus springt"
+);
 
-uppercase!(upper_en, Locale::EN, "foo BAR BaZ bike", "FOO BAR BAZ BIKE");
+case!(
+    german_mixed_case_input,
+    Case::Sentence,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "der schnelle braune Fuchs springt",
+    "Der schnelle braune Fuchs springt"
+);
 
-uppercase!(upper_es, Locale::ES, "hola MUNDo", "HOLA MUNDO");
+case!(
+    german_not_nouns,
+    Case::Sentence,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "die kommende woche ist jederzeit senkrecht und derart",
+    "Die kommende Woche ist jederzeit senkrecht und derart"
+);
 
-uppercase!(
-    upper_tr,
-    Locale::TR,
-    "foo BAR BaZ ILIK İLE",
-    "FOO BAR BAZ ILIK İLE"
+titlecase!(
+    german_compounds,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "die e-mail-adresse der gemeinschaft am bahnhof",
+    "Die E-Mail-Adresse der Gemeinschaft am Bahnhof"
 );
 
-macro_rules! sentencecase {
-    ($name:ident, $locale:expr, $input:expr, $expected:expr) => {
-        #[test]
-        fn $name() {
-            let actual = sentencecase($input, $locale).unwrap();
-            assert_eq!(actual, $expected);
-        }
-    };
-}
+titlecase!(
+    german_overrides,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().overrides(vec!["iPhone"]).build(),
+    "das iphone der zukunft",
+    "Das iPhone der Zukunft"
+);
 
-sentencecase!(
-    sentence_en,
-    Locale::EN,
-    "insert BIKE here",
-    "Insert bike here"
+titlecase!(
+    french_article_noun,
+    Locale::FR,
+    StyleGuide::ImprimerieNationale,
+    StyleOptions::default(),
+    "LES MISÉRABLES",
+    "Les Misérables"
 );
 
-sentencecase!(sentence_es, Locale::ES, "hola MUNDO", "Hola mundo");
+titlecase!(
+    french_preposed_adjective,
+    Locale::FR,
+    StyleGuide::ImprimerieNationale,
+    StyleOptions::default(),
+    "le petit prince",
+    "Le Petit Prince"
+);
+
+titlecase!(
+    french_preposed_numeral,
+    Locale::FR,
+    StyleGuide::ImprimerieNationale,
+    StyleOptions::default(),
+    "les trois mousquetaires",
+    "Les Trois Mousquetaires"
+);
+
+titlecase!(
+    french_elision,
+    Locale::FR,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "l'étranger",
+    "L'Étranger"
+);
+
+titlecase!(
+    french_no_article,
+    Locale::FR,
+    StyleGuide::ImprimerieNationale,
+    StyleOptions::default(),
+    "à la recherche du temps perdu",
+    "À la recherche du temps perdu"
+);
+
+titlecase!(
+    french_indefinite_article,
+    Locale::FR,
+    StyleGuide::ImprimerieNationale,
+    StyleOptions::default(),
+    "UNE SAISON EN ENFER",
+    "Une saison en enfer"
+);
+
+titlecase!(
+    french_accented_capitals,
+    Locale::FR,
+    StyleGuide::ImprimerieNationale,
+    StyleOptions::default(),
+    "l’école des femmes d’aujourd’hui",
+    "L’École des femmes d’aujourd’hui"
+);
+
+titlecase!(
+    italian_sentence_style,
+    Locale::IT,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "L'AMICA GENIALE",
+    "L'amica geniale"
+);
+
+titlecase!(
+    italian_major_words,
+    Locale::IT,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "L'AMICA GENIALE",
+    "L'Amica Geniale"
+);
+
+titlecase!(
+    italian_after_elision,
+    Locale::IT,
+    StyleGuide::CapitalizeAfterElision,
+    StyleOptions::default(),
+    "L'AMICA GENIALE",
+    "L'Amica geniale"
+);
+
+titlecase!(
+    italian_after_elided_preposition,
+    Locale::IT,
+    StyleGuide::CapitalizeAfterElision,
+    StyleOptions::default(),
+    "dell'arte della guerra e l'amore",
+    "Dell'Arte della guerra e l'amore"
+);
+
+titlecase!(
+    italian_after_elision_not_article,
+    Locale::IT,
+    StyleGuide::CapitalizeAfterElision,
+    StyleOptions::default(),
+    "quest'anno e l'amica",
+    "Quest'anno e l'amica"
+);
+
+titlecase!(
+    italian_articulated_prepositions,
+    Locale::IT,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "il nome della rosa e la storia dell'arte negli anni sugli alberi",
+    "Il Nome della Rosa e la Storia dell'Arte negli Anni sugli Alberi"
+);
+
+titlecase!(
+    italian_overrides,
+    Locale::IT,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().overrides(vec!["Zeno"]).build(),
+    "la coscienza di zeno",
+    "La coscienza di Zeno"
+);
+
+titlecase!(
+    portuguese_brazil,
+    "pt-BR",
+    StyleGuide::AssociacaoBrasileiraDeNormasTecnicas,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["Brás Cubas"])
+        .build(),
+    "MEMÓRIAS PÓSTUMAS DE BRÁS CUBAS",
+    "Memórias póstumas de Brás Cubas"
+);
+
+titlecase!(
+    portuguese_brazil_colon_month,
+    "pt_BR",
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "Lisboa: Uma Viagem Em Janeiro",
+    "Lisboa: uma viagem em janeiro"
+);
+
+titlecase!(
+    portuguese_portugal,
+    "pt-PT",
+    StyleGuide::AcademiaDasCienciasDeLisboa,
+    StyleOptions::default(),
+    "viagem pela terra num dia de janeiro",
+    "Viagem pela Terra num Dia de Janeiro"
+);
+
+titlecase!(
+    portuguese_portugal_colon,
+    "pt_PT",
+    StyleGuide::AcademiaDasCienciasDeLisboa,
+    StyleOptions::default(),
+    "lisboa: a cidade das sete colinas",
+    "Lisboa: A Cidade das Sete Colinas"
+);
+
+titlecase!(
+    portuguese_portugal_default,
+    "pt-PT",
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "viagem pela terra num dia de janeiro",
+    "Viagem pela Terra num Dia de Janeiro"
+);
+
+titlecase!(
+    portuguese_default,
+    "pt",
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "viagem pela terra num dia de janeiro",
+    "Viagem pela terra num dia de janeiro"
+);
+
+titlecase!(
+    portuguese_hyphens,
+    Locale::PT,
+    "acl",
+    StyleOptions::default(),
+    "o guarda-chuva do pé-de-meia",
+    "O Guarda-Chuva do Pé-de-Meia"
+);
+
+titlecase!(
+    dutch_ij_digraph,
+    Locale::NL,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "IJSLAND EN HET IJZER",
+    "IJsland en het ijzer"
+);
+
+titlecase!(
+    dutch_major_words,
+    Locale::NL,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "de reis van de heer van der berg naar ijmuiden",
+    "De Reis van de Heer van der Berg naar IJmuiden"
+);
+
+titlecase!(
+    dutch_contractions,
+    Locale::NL,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "'S MORGENS IN 'S-HERTOGENBOSCH",
+    "'s Morgens in 's-Hertogenbosch"
+);
+
+titlecase!(
+    azerbaijani_dotted_i,
+    Locale::AZ,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "İLİN İLK İŞİ VƏ İSTİ ÇAY İLƏ QIŞ",
+    "İlin İlk İşi və İsti Çay ilə Qış"
+);
+
+titlecase!(
+    azerbaijani_particles,
+    "az-Latn",
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "bakı ki gözəldir ya da şəki",
+    "Bakı ki Gözəldir ya da Şəki"
+);
+
+titlecase!(
+    greek_sentence_style,
+    Locale::EL,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "άλλος ΕΝΑΣ ΚΟΣΜΟΣ",
+    "Άλλος ενας κοσμος"
+);
+
+titlecase!(
+    catalan_iec,
+    Locale::CA,
+    StyleGuide::InstitutDEstudisCatalans,
+    StyleOptions::default(),
+    "LA COL·LECCIÓ D'ART DE L'HOME",
+    "La col·lecció d'art de l'home"
+);
+
+titlecase!(
+    catalan_major_words,
+    Locale::CA,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "la col·lecció d'art de l'home pels carrers dels pobles",
+    "La Col·lecció d'Art de l'Home pels Carrers dels Pobles"
+);
+
+titlecase!(
+    catalan_enclitics,
+    Locale::CA,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "digues-m'ho al teu germà",
+    "Digues-m'ho al Teu Germà"
+);
+
+titlecase!(
+    polish_sentence_style,
+    Locale::PL,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().overrides(vec!["Litwie"]).build(),
+    "OSTATNI ZAJAZD NA LITWIE",
+    "Ostatni zajazd na Litwie"
+);
+
+titlecase!(
+    polish_major_words,
+    Locale::PL,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "wiedźmin i pani jeziora w krainie z baśni",
+    "Wiedźmin i Pani Jeziora w Krainie z Baśni"
+);
+
+titlecase!(
+    polish_major_words_subtitle,
+    Locale::PL,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "ogniem i mieczem: w czasach wojny",
+    "Ogniem i Mieczem: W Czasach Wojny"
+);
+
+titlecase!(
+    czech_major_words,
+    Locale::CS,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "cesta k domu s přítelem v noci",
+    "Cesta k Domu s Přítelem v Noci"
+);
+
+titlecase!(
+    czech_leading_preposition,
+    Locale::CS,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "V ZAJETÍ",
+    "V zajetí"
+);
+
+titlecase!(
+    slovak_major_words,
+    Locale::SK,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "rozprávky zo slovenska o zbojníkoch",
+    "Rozprávky zo Slovenska o Zbojníkoch"
+);
+
+case!(
+    czech_sentence_overrides,
+    Case::Sentence,
+    Locale::CS,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["Karlem Čapkem"])
+        .build(),
+    "HOVORY S KARLEM ČAPKEM",
+    "Hovory s Karlem Čapkem"
+);
+
+titlecase!(
+    serbian_digraphs,
+    Locale::SR,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "LJUBAV I MRŽNJA U DŽUNGLI",
+    "Ljubav i mržnja u džungli"
+);
+
+titlecase!(
+    serbian_digraph_not_acronym,
+    Locale::SR,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "NJegoš i LJubav",
+    "Njegoš i Ljubav"
+);
+
+titlecase!(
+    serbian_precomposed_digraphs,
+    Locale::SR,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "ǆungla i ǉubav",
+    "ǅungla i ǈubav"
+);
+
+titlecase!(
+    serbian_cyrillic,
+    Locale::SR,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "на дрини ћуприја и мост на жепи",
+    "На Дрини Ћуприја и Мост на Жепи"
+);
+
+titlecase!(
+    serbian_mixed_script,
+    Locale::SR,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "ljubav i мржња na дрини",
+    "Ljubav i Мржња na Дрини"
+);
+
+titlecase!(
+    croatian_sentence_style,
+    Locale::HR,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().overrides(vec!["Zagreb"]).build(),
+    "NJIVE OKO ZAGREBA I ZAGREB",
+    "Njive oko zagreba i Zagreb"
+);
+
+titlecase!(
+    bosnian_major_words,
+    Locale::BS,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "džamija na obali",
+    "Džamija na Obali"
+);
+
+titlecase!(
+    russian_sentence_style,
+    Locale::RU,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().overrides(vec!["Москве"]).build(),
+    "ВОЙНА И МИР: ЖИЗНЬ В МОСКВЕ",
+    "Война и мир: жизнь в Москве"
+);
+
+titlecase!(
+    russian_capitalized_subtitle,
+    Locale::RU,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new()
+        .subtitles(Subtitles::Capitalize)
+        .build(),
+    "ВОЙНА И МИР: ЖИЗНЬ В ДЕРЕВНЕ",
+    "Война и мир: Жизнь в деревне"
+);
+
+titlecase!(
+    ukrainian_capitalized_subtitle,
+    Locale::UK,
+    StyleGuide::LanguageDefault,
+    StyleOptions::try_from("subtitles=capitalize").unwrap(),
+    "тіні забутих предків: повість",
+    "Тіні забутих предків: Повість"
+);
+
+titlecase!(
+    russian_major_words,
+    Locale::RU,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "война и мир: в поисках смысла",
+    "Война и Мир: В Поисках Смысла"
+);
+
+titlecase!(
+    ukrainian_major_words,
+    Locale::UK,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "тіні забутих предків та інші повісті",
+    "Тіні Забутих Предків та Інші Повісті"
+);
+
+titlecase!(
+    ukrainian_apostrophes,
+    Locale::UK,
+    StyleGuide::MajorWords,
+    StyleOptions::default(),
+    "п'ять історій про з’їзд і обʼєднання",
+    "П'ять Історій про З’їзд і Обʼєднання"
+);
+
+titlecase!(
+    ukrainian_apostrophe_first,
+    Locale::UK,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "М'ЯСО І РИБА",
+    "М'ясо і риба"
+);
+
+titlecase!(
+    irish_eclipsis,
+    Locale::GA,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "muintir na héireann i ngaillimh",
+    "Muintir na hÉireann i nGaillimh"
+);
+
+titlecase!(
+    irish_t_prefix,
+    Locale::GA,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "an t-athair agus an tsráid",
+    "An tAthair agus an tSráid"
+);
+
+titlecase!(
+    irish_h_root,
+    Locale::GA,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "a hata agus solas na hoíche",
+    "A Hata agus Solas na hOíche"
+);
+
+titlecase!(
+    irish_not_mutations,
+    Locale::GA,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "NGOZI AGUS AN TSUNAMI",
+    "Ngozi agus an Tsunami"
+);
+
+titlecase!(
+    irish_mutations_not_acronyms,
+    Locale::GA,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "ÁR nATHAIR i bPÁIRC",
+    "Ár nAthair i bPáirc"
+);
+
+case!(
+    irish_sentence,
+    Case::Sentence,
+    Locale::GA,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["nGaillimh"])
+        .build(),
+    "Ár nAthair i nGaillimh",
+    "Ár n-athair i nGaillimh"
+);
+
+titlecase!(
+    welsh_digraphs,
+    Locale::CY,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "LLANELLI A'R CYMOEDD",
+    "Llanelli a'r Cymoedd"
+);
+
+titlecase!(
+    welsh_digraph_not_acronym,
+    Locale::CY,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "LLanelli, CHwarel a RHydaman",
+    "Llanelli, Chwarel a Rhydaman"
+);
+
+titlecase!(
+    welsh_clitics,
+    Locale::CY,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "taith i'r gorllewin a ’r de: mae'n braf",
+    "Taith i'r Gorllewin a ’r De: Mae'n Braf"
+);
+
+case!(
+    welsh_sentence,
+    Case::Sentence,
+    Locale::CY,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["Caerdydd"])
+        .build(),
+    "Y LLYFRGELL YNG NGHAERDYDD A CAERDYDD",
+    "Y llyfrgell yng nghaerdydd a Caerdydd"
+);
+
+titlecase!(
+    lithuanian_sentence_style,
+    Locale::LT,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new()
+        .overrides(vec!["Vilniuje"])
+        .build(),
+    "ÌŠ ĮVAIRIŲ KRAŠTŲ VILNIUJE",
+    "Ìš įvairių kraštų Vilniuje"
+);
+
+case!(
+    lithuanian_sentence_dot_above,
+    Case::Sentence,
+    Locale::LT,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "KAIP ÌŠ JÌ",
+    "Kaip i\u{307}\u{300}š ji\u{307}\u{300}"
+);
+
+titlecase!(
+    georgian_no_titlecase,
+    Locale::KA,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "საქართველოს ისტორია",
+    "საქართველოს ისტორია"
+);
+
+titlecase!(
+    georgian_keeps_mtavruli,
+    Locale::KA,
+    StyleGuide::LanguageDefault,
+    StyleOptions::default(),
+    "ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ და მისი ისტორია",
+    "ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ და მისი ისტორია"
+);
+
+case!(
+    georgian_sentence,
+    Case::Sentence,
+    Locale::KA,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().overrides(vec!["GitHub"]).build(),
+    "github და საქართველო",
+    "GitHub და საქართველო"
+);
+
+macro_rules! lowercase {
+    ($name:ident, $locale:expr, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let actual = lowercase($input, $locale).unwrap();
+            assert_eq!(actual, $expected);
+        }
+    };
+}
+
+lowercase!(lower_en, Locale::EN, "foo BAR BaZ BIKE", "foo bar baz bike");
+
+lowercase!(lower_es, Locale::ES, "Hola MUNDO", "hola mundo");
+
+lowercase!(
+    lower_tr,
+    Locale::TR,
+    "foo BAR BaZ ILIK İLE",
+    "foo bar baz ılık ile"
+);
+
+lowercase!(
+    lower_de,
+    Locale::DE,
+    "DIE KLEINE NACHTMUSIK IN DER STADT",
+    "die kleine Nachtmusik in der Stadt"
+);
+
+lowercase!(lower_az, "azərbaycan", "İSTİ QIŞ", "isti qış");
+
+lowercase!(lower_el_final_sigma, Locale::EL, "ΟΔΟΣ ΣΟΦΟΣ", "οδος σοφος");
+
+lowercase!(lower_el_misplaced_sigma, Locale::EL, "ςοφοσ", "σοφος");
+
+lowercase!(lower_ca, Locale::CA, "COL·LECCIÓ", "col·lecció");
+
+lowercase!(lower_pl, Locale::PL, "IWONA I IGOR", "iwona i igor");
+
+lowercase!(lower_sr_precomposed, Locale::SR, "ǄEP I ǇUDI", "ǆep i ǉudi");
+
+lowercase!(
+    lower_ru,
+    Locale::RU,
+    "ПРЕСТУПЛЕНИЕ И НАКАЗАНИЕ",
+    "преступление и наказание"
+);
+
+lowercase!(
+    lower_ga,
+    Locale::GA,
+    "An tAthair i bPáirc",
+    "an t-athair i bpáirc"
+);
+
+lowercase!(
+    lower_cy,
+    Locale::CY,
+    "LLANELLI A'R CYMOEDD",
+    "llanelli a'r cymoedd"
+);
+
+lowercase!(
+    lower_lt_precomposed,
+    Locale::LT,
+    "Ì Í Ĩ",
+    "i\u{307}\u{300} i\u{307}\u{301} i\u{307}\u{303}"
+);
+
+lowercase!(
+    lower_lt_more_above,
+    Locale::LT,
+    "J\u{303} Į\u{303} I\u{328}\u{301} IS",
+    "j\u{307}\u{303} į\u{307}\u{303} i\u{307}\u{328}\u{301} is"
+);
+
+lowercase!(lower_ka, Locale::KA, "ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ", "საქართველო");
+
+macro_rules! uppercase {
+    ($name:ident, $locale:expr, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let actual = uppercase($input, $locale).unwrap();
+            assert_eq!(actual, $expected);
+        }
+    };
+}
+
+uppercase!(upper_en, Locale::EN, "foo BAR BaZ bike", "FOO BAR BAZ BIKE");
+
+uppercase!(upper_es, Locale::ES, "hola MUNDo", "HOLA MUNDO");
+
+uppercase!(
+    upper_tr,
+    Locale::TR,
+    "foo BAR BaZ ILIK İLE",
+    "FOO BAR BAZ ILIK İLE"
+);
+
+uppercase!(upper_de, Locale::DE, "die straße", "DIE STRASSE");
+
+uppercase!(upper_fr, Locale::FR, "l'école à noël", "L'ÉCOLE À NOËL");
+
+uppercase!(upper_az, Locale::AZ, "isti qış", "İSTİ QIŞ");
+
+uppercase!(upper_el_tonos, Locale::EL, "Καλημέρα", "ΚΑΛΗΜΕΡΑ");
+
+uppercase!(upper_el_dialytika, Locale::EL, "αϋπνία", "ΑΫΠΝΙΑ");
+
+uppercase!(
+    upper_el_stressed_diphthong,
+    Locale::EL,
+    "Μάιος και άυλος ρολόι",
+    "ΜΑΪΟΣ ΚΑΙ ΑΫΛΟΣ ΡΟΛΟΪ"
+);
+
+uppercase!(upper_ca, Locale::CA, "la col·lecció", "LA COL·LECCIÓ");
+
+uppercase!(
+    upper_ca_legacy_geminate,
+    Locale::CA,
+    "la col.lecció",
+    "LA COL·LECCIÓ"
+);
+
+uppercase!(
+    upper_ca_not_geminate,
+    Locale::CA,
+    "hotel.Lleida, portal.lavanguardia.cat i el Gral.López",
+    "HOTEL.LLEIDA, PORTAL.LAVANGUARDIA.CAT I EL GRAL.LÓPEZ"
+);
+
+lowercase!(
+    lower_ca_not_geminate,
+    Locale::CA,
+    "WWW.PORTAL.LAVANGUARDIA.CAT",
+    "www.portal.lavanguardia.cat"
+);
+
+uppercase!(
+    upper_sk,
+    Locale::SK,
+    "vianoce v bratislave",
+    "VIANOCE V BRATISLAVE"
+);
+
+uppercase!(
+    upper_hr_digraphs,
+    Locale::HR,
+    "Džep i Ljudi",
+    "DŽEP I LJUDI"
+);
+
+uppercase!(upper_sr_cyrillic, Locale::SR, "Џеп и људи", "ЏЕП И ЉУДИ");
+
+uppercase!(
+    upper_uk_apostrophe,
+    Locale::UK,
+    "пʼять м’ясних страв",
+    "ПʼЯТЬ М’ЯСНИХ СТРАВ"
+);
+
+uppercase!(
+    upper_ga,
+    Locale::GA,
+    "ár n-athair na héireann",
+    "ÁR nATHAIR NA hÉIREANN"
+);
+
+uppercase!(
+    upper_ga_h_root,
+    Locale::GA,
+    "a hata agus a halla",
+    "A HATA AGUS A HALLA"
+);
+
+uppercase!(
+    upper_ga_names,
+    Locale::GA,
+    "Kylian Mbappé agus Ngozi",
+    "KYLIAN MBAPPÉ AGUS NGOZI"
+);
+
+uppercase!(
+    upper_ga_not_t_prefix,
+    Locale::GA,
+    "tsunami mór",
+    "TSUNAMI MÓR"
+);
+
+uppercase!(
+    upper_cy,
+    Locale::CY,
+    "Llanelli a'r Rhondda",
+    "LLANELLI A'R RHONDDA"
+);
+
+uppercase!(
+    upper_lt_dot_above,
+    Locale::LT,
+    "i\u{307}\u{300} ji\u{307}\u{303} į\u{307}\u{301} ė",
+    "I\u{300} JI\u{303} Į\u{301} Ė"
+);
+
+case!(
+    upper_de_capital_eszett,
+    Case::Upper,
+    Locale::DE,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().eszett(Eszett::Capital).build(),
+    "die straße",
+    "DIE STRAẞE"
+);
+
+uppercase!(upper_ka_mtavruli, Locale::KA, "საქართველო", "ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ");
+
+case!(
+    upper_ka_keep_mkhedruli,
+    Case::Upper,
+    Locale::KA,
+    StyleGuide::LanguageDefault,
+    StyleOptionsBuilder::new().mtavruli(Mtavruli::Keep).build(),
+    "საქართველო და Tbilisi",
+    "საქართველო და TBILISI"
+);
+
+macro_rules! sentencecase {
+    ($name:ident, $locale:expr, $input:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let actual = sentencecase($input, $locale).unwrap();
+            assert_eq!(actual, $expected);
+        }
+    };
+}
+
+sentencecase!(
+    sentence_en,
+    Locale::EN,
+    "insert BIKE here",
+    "Insert bike here"
+);
+
+sentencecase!(
+    sentence_en_punctuation,
+    Locale::EN,
+    "“why NOT?”",
+    "“Why not?”"
+);
+
+sentencecase!(sentence_es, Locale::ES, "hola MUNDO", "Hola mundo");
+
+sentencecase!(
+    sentence_es_punctuation,
+    Locale::ES,
+    "¡HOLA mundo!",
+    "¡Hola mundo!"
+);
 
 sentencecase!(sentence_tr, Locale::TR, "ilk DAVRANSIN", "İlk davransın");
+
+sentencecase!(
+    sentence_de,
+    Locale::DE,
+    "vergessen wir die vergangenheit",
+    "Vergessen wir die Vergangenheit"
+);
+
+sentencecase!(sentence_fr, Locale::FR, "L'ÉTÉ INDIEN", "L'été indien");
+
+sentencecase!(
+    sentence_it,
+    Locale::IT,
+    "UN'ALTRA STORIA DELL'ARTE",
+    "Un'altra storia dell'arte"
+);
+
+sentencecase!(sentence_nl, Locale::NL, "'t is ijskoud", "'t Is ijskoud");
+
+sentencecase!(sentence_nl_ij, Locale::NL, "ijs en weder", "IJs en weder");
+
+sentencecase!(sentence_az, Locale::AZ, "İSTİ İLİN QIŞI", "İsti ilin qışı");
+
+sentencecase!(sentence_el, Locale::EL, "ΚΑΛΗΜΕΡΑ ΦΙΛΟΣ", "Καλημερα φιλος");
+
+sentencecase!(
+    sentence_ca,
+    Locale::CA,
+    "L'ESCOLA D'INTEL.LIGÈNCIA",
+    "L'escola d'intel·ligència"
+);
@@ -1,15 +1,24 @@
 # SPDX-FileCopyrightText: © 2023 Caleb Maclennan <caleb@alerque.com>
 # SPDX-License-Identifier: LGPL-3.0-only
 
+import pytest
+
 from decasify import (
     case,
     titlecase,
     lowercase,
     uppercase,
     sentencecase,
+    supported_styles,
+    supported_locales,
     Case,
     Locale,
     StyleGuide,
+    StyleFallback,
+    Acronyms,
+    Eszett,
+    Mtavruli,
+    Subtitles,
     version,
 )
 
@@ -20,6 +29,8 @@ def test_isfuction():
     assert callable(lowercase)
     assert callable(uppercase)
     assert callable(sentencecase)
+    assert callable(supported_styles)
+    assert callable(supported_locales)
     assert version.startswith("v")
 
 
@@ -48,6 +59,19 @@ class TestTitlecase:
     def test_style_overrides(self):
         assert titlecase("foo bar", Locale.EN, StyleGuide.DaringFireball, overrides=["fOO"]) == "fOO Bar"
 
+    def test_unsupported_style_guide(self):
+        with pytest.raises(ValueError):
+            titlecase("foo", Locale.TR, StyleGuide.ChicagoManualOfStyle)
+
+    def test_style_fallback(self):
+        text = "foo: a baz"
+        assert titlecase(text, Locale.EN, StyleGuide.RealAcademiaEspanola, fallback=StyleFallback.LanguageDefault) == "Foo: A Baz"
+        assert titlecase(text, Locale.EN, StyleGuide.RealAcademiaEspanola, fallback=StyleFallback.Closest) == "Foo: a Baz"
+
+    def test_acronyms(self):
+        text = "NASA and the iPhone"
+        assert titlecase(text, Locale.EN, StyleGuide.ChicagoManualOfStyle, acronyms=Acronyms.Keep) == text
+
     def test_turkish_characters(self):
         text = "İLKİ ILIK ÖĞLEN"
         outp = "İlki Ilık Öğlen"
@@ -82,6 +106,24 @@ class TestUppercase:
         outp = "İLKİ ILIK ÖĞLEN"
         assert uppercase(text, Locale.TR) == outp
 
+    def test_german_eszett(self):
+        text = "die Straße"
+        assert uppercase(text, Locale.DE) == "DIE STRASSE"
+        assert case(text, Case.Upper, Locale.DE, eszett=Eszett.Capital) == "DIE STRAẞE"
+
+    def test_georgian_mtavruli(self):
+        text = "საქართველო"
+        assert uppercase(text, Locale.KA) == "ᲡᲐᲥᲐᲠᲗᲕᲔᲚᲝ"
+        assert case(text, Case.Upper, Locale.KA, mtavruli=Mtavruli.Keep) == text
+
+    def test_russian_subtitles(self):
+        text = "война и мир: роман"
+        assert case(text, Case.Title, Locale.RU) == "Война и мир: роман"
+        assert (
+            case(text, Case.Title, Locale.RU, subtitles=Subtitles.Capitalize)
+            == "Война и мир: Роман"
+        )
+
 
 class TestSentencecase:
     def test_english_defaults(self):
@@ -93,3 +135,16 @@ class TestSentencecase:
         text = "ilk DAVRANSIN"
         outp = "İlk davransın"
         assert sentencecase(text, Locale.TR) == outp
+
+    def test_german_nouns(self):
+        text = "vergessen wir die vergangenheit"
+        outp = "Vergessen wir die Vergangenheit"
+        assert sentencecase(text, Locale.DE) == outp
+
+
+class TestCapabilities:
+    def test_supported_styles(self):
+        assert supported_styles(Locale.TR) == [StyleGuide.LanguageDefault, StyleGuide.TurkishLanguageInstitute]
+
+    def test_supported_locales(self):
+        assert supported_locales(StyleGuide.RealAcademiaEspanola) == [Locale.ES]
@@ -3,15 +3,26 @@
 
 #let _plugin = plugin("decasify.wasm")
 
-#let decasify-string(text, case, lang, style, overrides: none) = {
-  let overrides = if overrides != none { bytes(overrides.join(",")) } else { bytes("") }
-  str(_plugin.case(bytes(text), bytes(case), bytes(lang), bytes(style), overrides))
+#let _options(overrides, options) = {
+  let settings = ()
+  if options != none { settings.push(options) }
+  if overrides != none {
+    // Commas, semicolons, and backslashes in overrides are escaped as in the options syntax
+    let escaped = overrides.map(word => word.replace(regex("[\\\\,;]"), m => "\\" + m.text))
+    settings.push("overrides=" + escaped.join(","))
+  }
+  bytes(settings.join(";", default: ""))
+}
+
+#let decasify-string(text, case, lang, style, overrides: none, options: none) = {
+  let options = _options(overrides, options)
+  str(_plugin.case(bytes(text), bytes(case), bytes(lang), bytes(style), options))
 }
 
-#let decasify(body, case, style: "default", overrides: none) = {
+#let decasify(body, case, style: "default", overrides: none, options: none) = {
   show regex(".+"): it => {
     if it.func() == text {
-      decasify-string(it.text, case, text.lang, style, overrides: overrides)
+      decasify-string(it.text, case, text.lang, style, overrides: overrides, options: options)
     } else {
       it
     }
@@ -19,15 +30,15 @@
   body
 }
 
-#let titlecase-string(text, lang, style, overrides: none) = {
-  let overrides = if overrides != none { bytes(overrides.join(",")) } else { bytes("") }
-  str(_plugin.titlecase(bytes(text), bytes(lang), bytes(style), overrides))
+#let titlecase-string(text, lang, style, overrides: none, options: none) = {
+  let options = _options(overrides, options)
+  str(_plugin.titlecase(bytes(text), bytes(lang), bytes(style), options))
 }
 
-#let titlecase(body, style: "default", overrides: none) = {
+#let titlecase(body, style: "default", overrides: none, options: none) = {
   show regex(".+"): it => {
     if it.func() == text {
-      titlecase-string(it.text, text.lang, style, overrides: overrides)
+      titlecase-string(it.text, text.lang, style, overrides: overrides, options: options)
     } else {
       it
     }
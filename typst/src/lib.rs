@@ -4,7 +4,7 @@
 use anyhow::{Error, Result};
 use wasm_minimal_protocol::{initiate_protocol, wasm_func};
 
-use decasify::{Case, Locale, StyleGuide, StyleOptions, StyleOptionsBuilder};
+use decasify::{Case, Locale, StyleGuide, StyleOptions};
 
 initiate_protocol!();
 
@@ -16,34 +16,22 @@ pub fn decasify(
     case: &[u8],
     lang: &[u8],
     style: &[u8],
-    overrides: &[u8],
+    options: &[u8],
 ) -> TypstResult {
     let chunk = String::from_utf8(data.to_vec())?;
     let case = Case::try_from(case)?;
     let locale = Locale::try_from(lang)?;
     let style = StyleGuide::try_from(style)?;
-    let overrides_str = String::from_utf8(overrides.to_vec())?;
-    let opts = if overrides_str.is_empty() {
-        StyleOptions::default()
-    } else {
-        let overrides = overrides_str.split(',').map(String::from).collect();
-        StyleOptionsBuilder::new().overrides(overrides).build()
-    };
+    let opts = StyleOptions::try_from(options)?;
     Ok(decasify::case(&chunk, case, locale, style, opts)?.into_bytes())
 }
 
 #[wasm_func]
-pub fn titlecase(data: &[u8], lang: &[u8], style: &[u8], overrides: &[u8]) -> TypstResult {
+pub fn titlecase(data: &[u8], lang: &[u8], style: &[u8], options: &[u8]) -> TypstResult {
     let chunk = String::from_utf8(data.to_vec())?;
     let locale = Locale::try_from(lang)?;
     let style = StyleGuide::try_from(style)?;
-    let overrides_str = String::from_utf8(overrides.to_vec())?;
-    let opts = if overrides_str.is_empty() {
-        StyleOptions::default()
-    } else {
-        let overrides = overrides_str.split(',').map(String::from).collect();
-        StyleOptionsBuilder::new().overrides(overrides).build()
-    };
+    let opts = StyleOptions::try_from(options)?;
     Ok(decasify::titlecase(&chunk, locale, style, opts)?.into_bytes())
 }
 